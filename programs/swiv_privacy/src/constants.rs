@@ -4,8 +4,42 @@ use anchor_lang::pubkey;
 pub const SEED_GLOBAL_CONFIG: &[u8] = b"global_config_v1";
 pub const SEED_POOL: &[u8] = b"pool";
 pub const SEED_BET: &[u8] = b"user_bet";
+pub const SEED_SOL_VAULT: &[u8] = b"sol_vault";
+pub const SEED_POSITION: &[u8] = b"position";
+pub const SEED_PROFILE: &[u8] = b"profile";
+pub const SEED_REFERRAL: &[u8] = b"referral";
+pub const SEED_REFERRER_TIER: &[u8] = b"referrer_tier";
+pub const SEED_LEADERBOARD: &[u8] = b"leaderboard";
+pub const SEED_SEASON: &[u8] = b"season";
+pub const SEED_SEASON_VAULT: &[u8] = b"season_vault";
+pub const SEED_SEASON_ENTRY: &[u8] = b"season_entry";
+pub const SEED_BONUS_VAULT: &[u8] = b"bonus_vault";
+pub const SEED_TREASURY: &[u8] = b"treasury";
+pub const SEED_TREASURY_VAULT: &[u8] = b"treasury_vault";
 pub const SEED_FIXED_MARKET: &[u8] = b"fixed_market"; // legacy
 pub const MAX_STRATEGY_LENGTH: usize = 32;
-pub const MERCY_BUFFER_DEFAULT: u64 = 500; 
+pub const MERCY_BUFFER_DEFAULT: u64 = 500; // bps of the final outcome
+pub const EARLY_WITHDRAW_PENALTY_BPS: u64 = 1000; // stays in the pot
+pub const CASH_OUT_PENALTY_MIN_BPS: u64 = 500; // at start_time
+pub const CASH_OUT_PENALTY_MAX_BPS: u64 = 5000; // at end_time
+pub const REFERRAL_SHARE_BPS_DEFAULT: u64 = 2000; // of the entry fee
 pub const DISCRIMINATOR_SIZE: usize = 8;
-pub const PERMISSION_PROGRAM_ID: Pubkey = pubkey!("BTWAqWNBmF2TboMh3fxMJfgR16xGHYD7Kgr2dPwbRPBi");
\ No newline at end of file
+pub const PERMISSION_PROGRAM_ID: Pubkey = pubkey!("BTWAqWNBmF2TboMh3fxMJfgR16xGHYD7Kgr2dPwbRPBi");
+pub const SEED_PERMISSION_GROUP: &[u8] = b"group:"; // MagicBlock access-control
+pub const SEED_PERMISSION: &[u8] = b"permission:"; // MagicBlock access-control
+pub const MAX_PERMISSION_MEMBERS: usize = 32;
+pub const MAX_TRUSTED_VALIDATORS: usize = 8;
+pub const MIN_COMMIT_FREQUENCY_MS: u32 = 1_000;
+pub const MAX_COMMIT_FREQUENCY_MS: u32 = 86_400_000; // 1 day
+pub const MAX_POSITION_BETS: usize = 16;
+pub const MAX_REQUEST_ID_LEN: usize = 32; // PDA seed limit
+pub const MAX_ACTIVE_POOLS: usize = 32;
+pub const MAX_PAYOUT_TIERS: usize = 4;
+pub const MAX_LEADERBOARD_ENTRIES: usize = 100;
+pub const MAX_SEASON_ENTRIES: usize = 50;
+pub const MAX_SEASON_PRIZE_RANKS: usize = 16; // claimed_mask bits
+pub const SEASON_CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60; // then sweep_season
+pub const BONUS_CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60; // then reclaim_bonus_reward
+pub const MAX_TRACKED_SERIES: usize = 8;
+pub const MAX_TREASURY_DESTINATIONS: usize = 8;
+pub const STREAK_BONUS_STEP_BPS: u64 = 500; // per consecutive scoring round
@@ -46,4 +46,74 @@ pub enum CustomError {
     RevealWindowExpired,
     #[msg("Instruction has been removed in the Pool refactor.")]
     InstructionDeprecated,
+    #[msg("Permission group exceeds the maximum number of members.")]
+    TooManyPermissionMembers,
+    #[msg("Bet is still delegated to the ephemeral rollup. Undelegate it first.")]
+    BetStillDelegated,
+    #[msg("Validator is not in the trusted TEE validator allowlist.")]
+    ValidatorNotTrusted,
+    #[msg("Too many trusted validators.")]
+    TooManyTrustedValidators,
+    #[msg("Token mint has an extension that pools cannot support safely.")]
+    UnsupportedMintExtension,
+    #[msg("Vault accounts for the pool's stake asset are missing.")]
+    MissingVaultAccounts,
+    #[msg("Amount must be greater than zero and within the bet deposit.")]
+    InvalidAmount,
+    #[msg("Bet is below the pool minimum.")]
+    BetTooSmall,
+    #[msg("Bet exceeds the pool maximum.")]
+    BetTooLarge,
+    #[msg("Maximum number of bets per user reached for this pool.")]
+    TooManyBets,
+    #[msg("Pool deposit cap reached.")]
+    PoolCapReached,
+    #[msg("User has too many active pools.")]
+    TooManyActivePools,
+    #[msg("Request id is too long.")]
+    RequestIdTooLong,
+    #[msg("Invalid referrer.")]
+    InvalidReferrer,
+    #[msg("Nothing to claim.")]
+    NothingToClaim,
+    #[msg("Invalid payout tiers.")]
+    InvalidPayoutTiers,
+    #[msg("Leaderboard account required for tiered pools.")]
+    LeaderboardRequired,
+    #[msg("Season accounts required for pools linked to a season.")]
+    SeasonRequired,
+    #[msg("Season does not match.")]
+    SeasonMismatch,
+    #[msg("Season has not ended yet.")]
+    SeasonNotEnded,
+    #[msg("Invalid season prize shares.")]
+    InvalidSeasonPrizes,
+    #[msg("Not ranked for a season prize.")]
+    NotRanked,
+    #[msg("Bettor profile required for series pools.")]
+    ProfileRequired,
+    #[msg("Destination is not on the treasury allowlist.")]
+    DestinationNotAllowed,
+    #[msg("Treasury withdrawal limit for this epoch exceeded.")]
+    TreasuryLimitExceeded,
+    #[msg("Too many treasury destinations.")]
+    TooManyDestinations,
+    #[msg("Bet permission account is not initialized.")]
+    PermissionNotInitialized,
+    #[msg("Permission group does not include the bet owner.")]
+    NotAGroupMember,
+    #[msg("Pool is delegated to the ephemeral rollup. Undelegate it first.")]
+    PoolDelegated,
+    #[msg("No trusted validators configured.")]
+    NoTrustedValidators,
+    #[msg("Commit frequency out of range.")]
+    InvalidCommitFrequency,
+    #[msg("Position already indexes the maximum number of bets for this pool.")]
+    PositionFull,
+    #[msg("Claim window has closed.")]
+    ClaimWindowClosed,
+    #[msg("Claim window is still open.")]
+    ClaimWindowOpen,
+    #[msg("treasury_wallet is deprecated; use the per-mint Treasury.")]
+    TreasuryWalletDeprecated,
 }
\ No newline at end of file
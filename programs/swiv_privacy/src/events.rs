@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::PayoutBasis;
 
 // --- GLOBAL & ADMIN ---
 #[event]
@@ -12,6 +13,19 @@ pub struct PauseChanged {
     pub is_paused: bool,
 }
 
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub treasury_wallet: Pubkey,
+    pub protocol_fee_bps: u64,
+    pub referral_share_bps: u64,
+}
+
 #[event]
 pub struct PoolCreated {
     pub pool_name: String,
@@ -19,6 +33,27 @@ pub struct PoolCreated {
     pub end_time: i64,
 }
 
+#[event]
+pub struct PoolLimitsUpdated {
+    pub pool: Pubkey,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub max_bets_per_user: u32,
+    pub max_total_deposits: u64,
+    pub one_bet_per_user: bool,
+}
+
+#[event]
+pub struct TrustedValidatorsUpdated {
+    pub validators: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ReferrerTierSet {
+    pub referrer: Pubkey,
+    pub share_bps: u64,
+}
+
 
 // --- BETTING ---
 #[event]
@@ -42,12 +77,100 @@ pub struct BetUpdated {
     pub new_target: u64,
 }
 
-// --- SETTLEMENT ---
 #[event]
-pub struct RevealRequested {
+pub struct StakeIncreased {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub new_deposit: u64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub penalty: u64,
+    pub new_deposit: u64,
+}
+
+#[event]
+pub struct BetCashedOut {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub payout: u64,
+    pub penalty: u64,
+}
+
+#[event]
+pub struct BetPermissionsUpdated {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub group: Pubkey,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub pool: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+// --- DELEGATION ---
+#[event]
+pub struct BetDelegated {
     pub bet_address: Pubkey,
-    pub request_id: String,
-    pub timestamp: i64,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub validator: Option<Pubkey>,
+    pub commit_frequency_ms: u32,
+}
+
+#[event]
+pub struct BetUndelegated {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub undelegated_by: Pubkey,
+    pub forced: bool, // True if undelegated by a keeper after end_time
+}
+
+#[event]
+pub struct BetCommitted {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub committed_by: Pubkey,
+}
+
+#[event]
+pub struct PoolDelegated {
+    pub pool: Pubkey,
+    pub validator: Pubkey,
+}
+
+#[event]
+pub struct PoolCommitted {
+    pub pool: Pubkey,
+    pub total_weight: u128,
+}
+
+#[event]
+pub struct PoolUndelegated {
+    pub pool: Pubkey,
+    pub total_weight: u128,
+}
+
+// --- SETTLEMENT ---
+#[event]
+pub struct PoolResolved {
+    pub pool: Pubkey,
+    pub final_outcome: u64,
+    pub resolution_ts: i64,
 }
 
 #[event]
@@ -58,6 +181,37 @@ pub struct BetRevealed {
     pub decrypted_target: u64, 
 }
 
+#[event]
+pub struct BetWeighted {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub accuracy_score: u64,
+    pub time_bonus: u64,
+    pub conviction_bonus: u64,
+    pub streak_bonus: u64,
+    pub weight: u128,
+    pub pool_total_weight: u128,
+}
+
+#[event]
+pub struct WeightsFinalized {
+    pub pool: Pubkey,
+    pub total_weight: u128,
+    pub protocol_fee: u64,
+    pub locked_for_payouts: u64,
+    pub payout_basis: PayoutBasis,
+    pub rolled_over: u64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+}
+
 #[event]
 pub struct BetSettled {
     pub bet_address: Pubkey,
@@ -68,4 +222,84 @@ pub struct BetSettled {
     pub refund_amount: u64,
     pub referral_fee: u64,
     pub forced_by_admin: bool, // True if batch settled (forfeit applied)
-}
\ No newline at end of file
+}
+#[event]
+pub struct SeasonCreated {
+    pub season: Pubkey,
+    pub season_id: u32,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub fee_share_bps: u64,
+}
+
+#[event]
+pub struct SeasonPrizeClaimed {
+    pub season: Pubkey,
+    pub user: Pubkey,
+    pub rank: u8,
+    pub points: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonSwept {
+    pub season: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PoolFunded {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub sponsored_amount: u64,
+}
+
+#[event]
+pub struct BonusRewardFunded {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub bonus_total: u64,
+}
+
+#[event]
+pub struct BonusRewardClaimed {
+    pub bet_address: Pubkey,
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BonusRewardReclaimed {
+    pub pool: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryInitialized {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct TreasuryPolicyUpdated {
+    pub treasury: Pubkey,
+    pub allowed_destinations: Vec<Pubkey>,
+    pub epoch_limit: u64,
+    pub effective_epoch: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub withdrawn_in_epoch: u64,
+}
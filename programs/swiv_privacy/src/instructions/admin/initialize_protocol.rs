@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::GlobalConfig;
-use crate::constants::{SEED_GLOBAL_CONFIG};
+use crate::constants::{SEED_GLOBAL_CONFIG, REFERRAL_SHARE_BPS_DEFAULT};
 use crate::events::ProtocolInitialized;
 
 #[derive(Accounts)]
@@ -11,7 +11,7 @@ pub struct InitializeProtocol<'info> {
     #[account(
         init,
         payer = admin,
-        space = GlobalConfig::BASE_LEN,
+        space = GlobalConfig::LEN,
         seeds = [SEED_GLOBAL_CONFIG],
         bump
     )]
@@ -38,7 +38,8 @@ pub fn initialize_protocol(
     global_config.protocol_fee_bps = protocol_fee_bps;
 
     global_config.paused = false;
-    global_config.total_users = 0;
+    global_config.referral_share_bps = REFERRAL_SHARE_BPS_DEFAULT;
+    global_config.trusted_validators = Vec::new();
 
     emit!(ProtocolInitialized {
         admin: ctx.accounts.admin.key(),
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{GlobalConfig, StakeAsset, Treasury};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_TREASURY, SEED_TREASURY_VAULT};
+use crate::errors::CustomError;
+use crate::events::TreasuryInitialized;
+
+#[derive(Accounts)]
+#[instruction(mint_key: Pubkey)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Treasury::LEN,
+        seeds = [SEED_TREASURY, mint_key.as_ref()],
+        bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // --- TOKEN TREASURIES (omit for native SOL) ---
+    #[account(
+        address = mint_key @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [SEED_TREASURY_VAULT, mint_key.as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = global_config,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL TREASURY (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, mint_key.as_ref()],
+        bump
+    )]
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the fee vault for `mint_key` (default pubkey for native SOL). Pools
+/// in that asset pay their fees into it, so it must exist before they take bets.
+/// Starts with an empty allowlist; see set_treasury_policy.
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>, mint_key: Pubkey, epoch_limit: u64) -> Result<()> {
+    require!(epoch_limit > 0, CustomError::InvalidAmount);
+
+    let (asset, mint, vault, vault_bump) = match (&ctx.accounts.mint, &ctx.accounts.treasury_sol_vault) {
+        (Some(mint), None) => {
+            let vault = ctx.accounts.treasury_vault.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+            (StakeAsset::Token, mint.key(), vault.key(), ctx.bumps.treasury_vault.unwrap_or_default())
+        }
+        (None, Some(sol_vault)) => {
+            require_keys_eq!(mint_key, Pubkey::default(), CustomError::MarketMismatch);
+
+            // Keep the vault rent-exempt so small fee transfers succeed
+            let reserve = Rent::get()?.minimum_balance(0);
+            if sol_vault.lamports() < reserve {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.admin.to_account_info(),
+                            to: sol_vault.to_account_info(),
+                        },
+                    ),
+                    reserve - sol_vault.lamports(),
+                )?;
+            }
+            (StakeAsset::NativeSol, Pubkey::default(), sol_vault.key(), ctx.bumps.treasury_sol_vault.unwrap_or_default())
+        }
+        _ => return Err(CustomError::MissingVaultAccounts.into()),
+    };
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.mint = mint;
+    treasury.asset = asset;
+    treasury.vault_bump = vault_bump;
+    treasury.allowed_destinations = Vec::new();
+    treasury.epoch_limit = epoch_limit;
+    treasury.current_epoch = Clock::get()?.epoch;
+    treasury.withdrawn_in_epoch = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+    treasury.pending_destinations = Vec::new();
+    treasury.pending_epoch_limit = 0;
+    treasury.pending_from_epoch = None;
+
+    emit!(TreasuryInitialized {
+        treasury: treasury.key(),
+        mint,
+        vault,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::GlobalConfig;
+use crate::constants::{SEED_GLOBAL_CONFIG, REFERRAL_SHARE_BPS_DEFAULT};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: May still use the original layout, so it is checked by hand:
+    /// program-owned, GlobalConfig discriminator, admin at the same offset
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        owner = crate::ID
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows a GlobalConfig created before `referral_share_bps` and
+/// `trusted_validators` existed to `GlobalConfig::LEN`. `referral_share_bps`
+/// replaces the unused `total_users` and starts at the default; the validator
+/// list starts empty. No-op once migrated.
+pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+    let info = ctx.accounts.global_config.to_account_info();
+    let old_len = info.data_len();
+
+    {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= GlobalConfig::V1_LEN && data[..8] == *GlobalConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data[8..40] == ctx.accounts.admin.key().to_bytes(), CustomError::Unauthorized);
+    }
+
+    if old_len >= GlobalConfig::LEN {
+        msg!("Global Config already migrated");
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(GlobalConfig::LEN);
+    if info.lamports() < rent {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            rent - info.lamports(),
+        )?;
+    }
+
+    info.resize(GlobalConfig::LEN)?;
+
+    // Original layout ended at total_users: overwrite it with
+    // referral_share_bps; the zeroed bytes after it read as an empty
+    // trusted_validators list
+    if old_len == GlobalConfig::V1_LEN {
+        let mut data = info.try_borrow_mut_data()?;
+        data[GlobalConfig::BASE_LEN - 8..GlobalConfig::BASE_LEN]
+            .copy_from_slice(&REFERRAL_SHARE_BPS_DEFAULT.to_le_bytes());
+    }
+
+    msg!("Global Config Migrated: {} -> {} bytes", old_len, GlobalConfig::LEN);
+
+    Ok(())
+}
@@ -2,8 +2,20 @@ pub mod initialize_protocol;
 pub mod set_pause;
 pub mod update_config;
 pub mod transfer_admin;
+pub mod set_trusted_validators;
+pub mod migrate_global_config;
+pub mod set_referrer_tier;
+pub mod initialize_treasury;
+pub mod set_treasury_policy;
+pub mod withdraw_treasury;
 
 pub use initialize_protocol::*;
 pub use set_pause::*;
 pub use update_config::*;
-pub use transfer_admin::*;
\ No newline at end of file
+pub use transfer_admin::*;
+pub use set_trusted_validators::*;
+pub use migrate_global_config::*;
+pub use set_referrer_tier::*;
+pub use initialize_treasury::*;
+pub use set_treasury_policy::*;
+pub use withdraw_treasury::*;
\ No newline at end of file
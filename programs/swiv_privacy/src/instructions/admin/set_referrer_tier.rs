@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, ReferrerTier};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_REFERRER_TIER};
+use crate::errors::CustomError;
+use crate::events::ReferrerTierSet;
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct SetReferrerTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ReferrerTier::LEN,
+        seeds = [SEED_REFERRER_TIER, referrer.as_ref()],
+        bump
+    )]
+    pub referrer_tier: Account<'info, ReferrerTier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Overrides `GlobalConfig::referral_share_bps` for one referrer.
+pub fn set_referrer_tier(
+    ctx: Context<SetReferrerTier>,
+    referrer: Pubkey,
+    share_bps: u64,
+) -> Result<()> {
+    require!(share_bps <= 10000, CustomError::InvalidAmount);
+
+    let tier = &mut ctx.accounts.referrer_tier;
+    tier.referrer = referrer;
+    tier.share_bps = share_bps;
+    tier.bump = ctx.bumps.referrer_tier;
+
+    msg!("Referrer Tier Updated: {} -> {} bps", referrer, share_bps);
+
+    emit!(ReferrerTierSet { referrer, share_bps });
+
+    Ok(())
+}
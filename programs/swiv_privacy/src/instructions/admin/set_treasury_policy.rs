@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, Treasury};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_TREASURY};
+use crate::errors::CustomError;
+use crate::events::TreasuryPolicyUpdated;
+
+#[derive(Accounts)]
+pub struct SetTreasuryPolicy<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY, treasury.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+/// Replaces the destination allowlist and the per-epoch limit from the next
+/// epoch on. A later call before then overwrites the queued policy.
+pub fn set_treasury_policy(
+    ctx: Context<SetTreasuryPolicy>,
+    allowed_destinations: Vec<Pubkey>,
+    epoch_limit: u64,
+) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let epoch = Clock::get()?.epoch;
+
+    treasury.apply_pending_policy(epoch);
+    let effective_epoch = treasury.queue_policy(allowed_destinations, epoch_limit, epoch)?;
+
+    emit!(TreasuryPolicyUpdated {
+        treasury: treasury.key(),
+        allowed_destinations: treasury.pending_destinations.clone(),
+        epoch_limit,
+        effective_epoch,
+    });
+
+    Ok(())
+}
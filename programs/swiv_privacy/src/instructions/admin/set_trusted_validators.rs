@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalConfig;
+use crate::constants::{SEED_GLOBAL_CONFIG, MAX_TRUSTED_VALIDATORS};
+use crate::errors::CustomError;
+use crate::events::TrustedValidatorsUpdated;
+
+#[derive(Accounts)]
+pub struct SetTrustedValidators<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn set_trusted_validators(
+    ctx: Context<SetTrustedValidators>,
+    validators: Vec<Pubkey>,
+) -> Result<()> {
+    require!(validators.len() <= MAX_TRUSTED_VALIDATORS, CustomError::TooManyTrustedValidators);
+
+    ctx.accounts.global_config.trusted_validators = validators;
+
+    msg!("Trusted Validators Updated: {}", ctx.accounts.global_config.trusted_validators.len());
+
+    emit!(TrustedValidatorsUpdated {
+        validators: ctx.accounts.global_config.trusted_validators.clone(),
+    });
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::GlobalConfig;
 use crate::constants::SEED_GLOBAL_CONFIG;
 use crate::errors::CustomError;
+use crate::events::AdminTransferred;
 
 #[derive(Accounts)]
 pub struct TransferAdmin<'info> {
@@ -24,5 +25,10 @@ pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<
     
     msg!("Admin transferred from {} to {}", ctx.accounts.current_admin.key(), new_admin);
 
+    emit!(AdminTransferred {
+        previous_admin: ctx.accounts.current_admin.key(),
+        new_admin,
+    });
+
     Ok(())
 }
\ No newline at end of file
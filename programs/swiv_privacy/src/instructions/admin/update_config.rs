@@ -2,11 +2,13 @@ use anchor_lang::prelude::*;
 use crate::state::GlobalConfig;
 use crate::constants::SEED_GLOBAL_CONFIG;
 use crate::errors::CustomError;
+use crate::events::ConfigUpdated;
 
 #[derive(Accounts)]
 #[instruction(
     new_treasury: Option<Pubkey>, 
-    new_protocol_fee_bps: Option<u64>
+    new_protocol_fee_bps: Option<u64>,
+    new_referral_share_bps: Option<u64>
 )]
 pub struct UpdateConfig<'info> {
     #[account(mut)]
@@ -27,18 +29,30 @@ pub fn update_config(
     ctx: Context<UpdateConfig>,
     new_treasury: Option<Pubkey>,
     new_protocol_fee_bps: Option<u64>,
+    new_referral_share_bps: Option<u64>,
 ) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
-    if let Some(treasury) = new_treasury {
-        global_config.treasury_wallet = treasury;
-    }
+    // Deprecated: fees go to the per-mint Treasury vaults; the argument stays
+    // for client compatibility but can no longer be set
+    require!(new_treasury.is_none(), CustomError::TreasuryWalletDeprecated);
 
     if let Some(p_fee) = new_protocol_fee_bps {
         global_config.protocol_fee_bps = p_fee;
     }
 
+    if let Some(share) = new_referral_share_bps {
+        require!(share <= 10000, CustomError::InvalidAmount);
+        global_config.referral_share_bps = share;
+    }
+
     msg!("Global Config Updated");
 
+    emit!(ConfigUpdated {
+        treasury_wallet: global_config.treasury_wallet,
+        protocol_fee_bps: global_config.protocol_fee_bps,
+        referral_share_bps: global_config.referral_share_bps,
+    });
+
     Ok(())
 }
\ No newline at end of file
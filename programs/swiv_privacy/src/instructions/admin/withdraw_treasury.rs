@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{GlobalConfig, StakeAsset, Treasury};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_TREASURY, SEED_TREASURY_VAULT};
+use crate::errors::CustomError;
+use crate::events::TreasuryWithdrawn;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY, treasury.mint.as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// CHECK: Allowlisted token account (or wallet for native SOL), checked in the handler
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    // --- TOKEN TREASURIES (omit for native SOL) ---
+    #[account(
+        address = treasury.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, treasury.mint.as_ref()],
+        bump = treasury.vault_bump,
+        token::mint = mint,
+        token::authority = global_config,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL TREASURY (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, treasury.mint.as_ref()],
+        bump = treasury.vault_bump
+    )]
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let destination = ctx.accounts.destination.to_account_info();
+    let epoch = Clock::get()?.epoch;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    treasury.record_withdrawal(destination.key, amount, epoch)?;
+
+    match treasury.asset {
+        StakeAsset::Token => {
+            let mint = ctx.accounts.mint.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+            let token_program = ctx.accounts.token_program.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+            let treasury_vault = ctx.accounts.treasury_vault.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+
+            let seeds = &[SEED_GLOBAL_CONFIG, &[ctx.bumps.global_config]];
+            let signer = &[&seeds[..]];
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: treasury_vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: destination,
+                        authority: ctx.accounts.global_config.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+        StakeAsset::NativeSol => {
+            let sol_vault = ctx.accounts.treasury_sol_vault.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+
+            // The rent reserve stays in the vault
+            let reserve = Rent::get()?.minimum_balance(0);
+            require!(
+                sol_vault.lamports().saturating_sub(reserve) >= amount,
+                CustomError::InsufficientLiquidity
+            );
+
+            let mint_key = treasury.mint;
+            let seeds = &[SEED_TREASURY_VAULT, mint_key.as_ref(), &[treasury.vault_bump]];
+            let signer = &[&seeds[..]];
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: sol_vault.to_account_info(),
+                        to: destination,
+                    },
+                    signer,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    msg!("Treasury Withdrawal: {} to {}", amount, ctx.accounts.destination.key());
+
+    emit!(TreasuryWithdrawn {
+        treasury: treasury.key(),
+        mint: treasury.mint,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        epoch,
+        withdrawn_in_epoch: treasury.withdrawn_in_epoch,
+    });
+
+    Ok(())
+}
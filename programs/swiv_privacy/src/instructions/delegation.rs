@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserBet, Pool};
-use crate::constants::{SEED_BET};
+use crate::state::{UserBet, Pool, GlobalConfig};
+use crate::constants::{SEED_BET, SEED_GLOBAL_CONFIG, SEED_POOL, MIN_COMMIT_FREQUENCY_MS, MAX_COMMIT_FREQUENCY_MS};
 use crate::errors::CustomError;
+use crate::events::{BetCommitted, BetDelegated, BetUndelegated, PoolCommitted, PoolDelegated, PoolUndelegated};
 use ephemeral_rollups_sdk::anchor::{delegate, commit};
 use ephemeral_rollups_sdk::cpi::DelegateConfig;
-use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use ephemeral_rollups_sdk::ephem::{commit_accounts, commit_and_undelegate_accounts};
 
 // ------------------------------------------------------------------
 // DELEGATE INSTRUCTION
@@ -17,6 +18,12 @@ pub struct DelegateBet<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch
     )]
@@ -38,23 +45,44 @@ pub struct DelegateBet<'info> {
     
 }
 
-pub fn delegate_bet(ctx: Context<DelegateBet>, request_id: String) -> Result<()> {
-    // 1. Prepare Seeds for Signing (Since UserBet is a PDA)
+pub fn delegate_bet(
+    ctx: Context<DelegateBet>,
+    request_id: String,
+    validator: Option<Pubkey>,
+    commit_frequency_ms: Option<u32>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp < ctx.accounts.pool.end_time, CustomError::DurationTooShort);
+
+    // 1. Prepare Seeds (the SDK derives the bump itself)
     let pool_key = ctx.accounts.pool.key();
     let user_key = ctx.accounts.user.key();
-    let bump = ctx.accounts.user_bet.bump;
     
-    let seeds = &[
+    let seeds: &[&[u8]] = &[
         SEED_BET,
         pool_key.as_ref(),
         user_key.as_ref(),
         request_id.as_bytes(),
-        &[bump],
     ];
 
-    let config = DelegateConfig::default();
+    // 2. Pin a trusted TEE validator; by default schedule the first
+    //    automatic commit at pool close
+    if let Some(frequency) = commit_frequency_ms {
+        require!(
+            (MIN_COMMIT_FREQUENCY_MS..=MAX_COMMIT_FREQUENCY_MS).contains(&frequency),
+            CustomError::InvalidCommitFrequency
+        );
+    }
+    let config = DelegateConfig {
+        commit_frequency_ms: commit_frequency_ms.unwrap_or_else(|| {
+            commit_frequency_until(clock.unix_timestamp, ctx.accounts.pool.end_time)
+        }),
+        validator: Some(ctx.accounts.global_config.resolve_validator(validator)?),
+    };
     
-    // Let's follow the Logic:
+    let validator = config.validator;
+    let commit_frequency_ms = config.commit_frequency_ms;
+
     ctx.accounts.delegate_user_bet(
         &ctx.accounts.user, // Payer
         seeds,              // PDA Seeds for signing
@@ -62,9 +90,27 @@ pub fn delegate_bet(ctx: Context<DelegateBet>, request_id: String) -> Result<()>
     )?;
 
     msg!("Bet Delegated successfully");
+
+    emit!(BetDelegated {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: user_key,
+        pool: pool_key,
+        validator,
+        commit_frequency_ms,
+    });
     Ok(())
 }
 
+/// Milliseconds from `now` until `end_time`, so the validator's first periodic
+/// commit is scheduled for pool close (clamped to u32::MAX ms, ~49 days).
+/// This is only a schedule: nothing guarantees the commit lands before
+/// `resolve_pool`, so settlement still reads bets through `load_undelegated`
+/// and keepers bring them back with `force_undelegate_after_end`.
+fn commit_frequency_until(now: i64, end_time: i64) -> u32 {
+    let remaining_ms = end_time.saturating_sub(now).saturating_mul(1000);
+    u32::try_from(remaining_ms.max(1)).unwrap_or(u32::MAX)
+}
+
 // ------------------------------------------------------------------
 // UNDELEGATE INSTRUCTION
 // ------------------------------------------------------------------
@@ -106,5 +152,272 @@ pub fn undelegate_bet(ctx: Context<UndelegateBet>, _request_id: String) -> Resul
     )?;
 
     msg!("Bet Undelegated (Committed)");
+
+    emit!(BetUndelegated {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: ctx.accounts.user.key(),
+        pool: ctx.accounts.pool.key(),
+        undelegated_by: ctx.accounts.user.key(),
+        forced: false,
+    });
+    Ok(())
+}
+
+// ------------------------------------------------------------------
+// COMMIT INSTRUCTION
+// ------------------------------------------------------------------
+
+#[commit]
+#[derive(Accounts)]
+#[instruction(request_id: String)]
+pub struct CommitBet<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Owner of the bet being committed
+    pub bet_owner: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_BET, 
+            pool.key().as_ref(), 
+            bet_owner.key().as_ref(), 
+            request_id.as_bytes()
+        ],
+        bump = user_bet.bump,
+        constraint = user_bet.owner == bet_owner.key() @ CustomError::Unauthorized,
+    )]
+    pub user_bet: Account<'info, UserBet>,
+}
+
+/// Checkpoints the bet to the base layer while it stays in the TEE.
+/// The owner can commit at any time; once the pool has ended any keeper can,
+/// so every delegated bet is on the base layer before settlement reads it.
+pub fn commit_bet(ctx: Context<CommitBet>, _request_id: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let is_owner = ctx.accounts.payer.key() == ctx.accounts.bet_owner.key();
+    require!(
+        is_owner || clock.unix_timestamp >= ctx.accounts.pool.end_time,
+        CustomError::Unauthorized
+    );
+
+    commit_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.user_bet.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    msg!("Bet Committed (still delegated)");
+
+    emit!(BetCommitted {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: ctx.accounts.bet_owner.key(),
+        pool: ctx.accounts.pool.key(),
+        committed_by: ctx.accounts.payer.key(),
+    });
     Ok(())
-}
\ No newline at end of file
+}
+
+// ------------------------------------------------------------------
+// FORCE UNDELEGATE (KEEPER)
+// ------------------------------------------------------------------
+
+#[commit]
+#[derive(Accounts)]
+#[instruction(request_id: String)]
+pub struct ForceUndelegateAfterEnd<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Owner of the bet being undelegated
+    pub bet_owner: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_BET, 
+            pool.key().as_ref(), 
+            bet_owner.key().as_ref(), 
+            request_id.as_bytes()
+        ],
+        bump = user_bet.bump,
+        constraint = user_bet.owner == bet_owner.key() @ CustomError::Unauthorized,
+    )]
+    pub user_bet: Account<'info, UserBet>,
+}
+
+/// Permissionless: once the pool has ended any keeper can bring a bet back to
+/// the base layer so it can be settled.
+pub fn force_undelegate_after_end(ctx: Context<ForceUndelegateAfterEnd>, _request_id: String) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp >= ctx.accounts.pool.end_time, CustomError::SettlementTooEarly);
+
+    commit_and_undelegate_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.user_bet.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    msg!("Bet Force-Undelegated by keeper: {}", ctx.accounts.payer.key());
+
+    emit!(BetUndelegated {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: ctx.accounts.bet_owner.key(),
+        pool: ctx.accounts.pool.key(),
+        undelegated_by: ctx.accounts.payer.key(),
+        forced: true,
+    });
+    Ok(())
+}
+
+// ------------------------------------------------------------------
+// POOL DELEGATION
+// ------------------------------------------------------------------
+// Delegating the Pool lets `calculate_pool_outcome` accumulate
+// `total_weight` for every bet inside the TEE. The token vault stays on
+// the base layer; only the Pool accounting moves to the rollup.
+
+#[delegate]
+#[derive(Accounts)]
+pub struct DelegatePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        del,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump,
+        constraint = !pool.weight_finalized @ CustomError::AlreadySettled
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Only once betting has closed: a delegated Pool is owned by the delegation
+/// program, so base-layer `place_bet` / `increase_stake` could not write it.
+pub fn delegate_pool(ctx: Context<DelegatePool>, validator: Option<Pubkey>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp >= ctx.accounts.pool.end_time, CustomError::SettlementTooEarly);
+
+    let name = ctx.accounts.pool.name.clone();
+    let seeds: &[&[u8]] = &[SEED_POOL, name.as_bytes()];
+
+    // Persist the flag before the SDK copies the account into the delegation buffer
+    ctx.accounts.pool.is_delegated = true;
+    ctx.accounts.pool.exit(&crate::ID)?;
+
+    // Must land on the same TEE validator as the bets it accumulates
+    let validator = ctx.accounts.global_config.resolve_validator(validator)?;
+    let config = DelegateConfig {
+        validator: Some(validator),
+        ..DelegateConfig::default()
+    };
+
+    ctx.accounts.delegate_pool(
+        &ctx.accounts.admin,
+        seeds,
+        config,
+    )?;
+
+    msg!("Pool Delegated: {}", name);
+
+    emit!(PoolDelegated {
+        pool: ctx.accounts.pool.key(),
+        validator,
+    });
+    Ok(())
+}
+
+#[commit]
+#[derive(Accounts)]
+pub struct CommitPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Checkpoints the Pool back to the base layer while it stays delegated.
+/// Permissionless so a keeper can call it periodically.
+pub fn commit_pool(ctx: Context<CommitPool>) -> Result<()> {
+    commit_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.pool.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    msg!("Pool Committed. Total Weight: {}", ctx.accounts.pool.total_weight);
+
+    emit!(PoolCommitted {
+        pool: ctx.accounts.pool.key(),
+        total_weight: ctx.accounts.pool.total_weight,
+    });
+    Ok(())
+}
+
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegatePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+pub fn undelegate_pool(ctx: Context<UndelegatePool>) -> Result<()> {
+    ctx.accounts.pool.is_delegated = false;
+    ctx.accounts.pool.exit(&crate::ID)?;
+
+    commit_and_undelegate_accounts(
+        &ctx.accounts.admin,
+        vec![&ctx.accounts.pool.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    msg!("Pool Undelegated (Committed). Total Weight: {}", ctx.accounts.pool.total_weight);
+
+    emit!(PoolUndelegated {
+        pool: ctx.accounts.pool.key(),
+        total_weight: ctx.accounts.pool.total_weight,
+    });
+    Ok(())
+}
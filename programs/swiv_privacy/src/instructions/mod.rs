@@ -2,8 +2,12 @@ pub mod admin;
 pub mod pool;
 pub mod shared;
 pub mod delegation;
+pub mod permissions;
+pub mod season;
 
 pub use admin::*;
 pub use pool::*;
 pub use shared::*;
-pub use delegation::*;
\ No newline at end of file
+pub use delegation::*;
+pub use permissions::*;
+pub use season::*;
\ No newline at end of file
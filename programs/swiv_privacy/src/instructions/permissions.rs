@@ -1,69 +1,201 @@
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::access_control::{
-    CreateGroupCpiBuilder, CreatePermissionCpiBuilder
+    CreateGroupCpiBuilder, CreatePermissionCpiBuilder, UpdatePermissionCpiBuilder
+};
+use crate::state::{Pool, UserBet};
+use crate::constants::{
+    SEED_BET, SEED_PERMISSION, SEED_PERMISSION_GROUP, PERMISSION_PROGRAM_ID, MAX_PERMISSION_MEMBERS
 };
-use crate::state::UserBet;
 use crate::errors::CustomError;
+use crate::events::BetPermissionsUpdated;
+
+// ------------------------------------------------------------------
+// SHARED PERMISSION MODEL
+// ------------------------------------------------------------------
+// The delegated account is always the UserBet PDA:
+// - Group      = [SEED_PERMISSION_GROUP, group_id]  (first group_id = UserBet key)
+// - Permission = [SEED_PERMISSION, user_bet]
+// The bet owner is always a member of the group. Groups are immutable, so
+// membership changes re-point the permission to another group. Existing groups
+// (e.g. one shared keeper group) are reused instead of creating one per update:
+// the permission program has no instruction to close a superseded group.
+
+/// Owner first, then the extra members (deduplicated), capped at MAX_PERMISSION_MEMBERS.
+pub fn build_bet_members(owner: Pubkey, extra_members: &[Pubkey]) -> Result<Vec<Pubkey>> {
+    let mut members = vec![owner];
+    for member in extra_members {
+        if !members.contains(member) {
+            members.push(*member);
+        }
+    }
+
+    require!(members.len() <= MAX_PERMISSION_MEMBERS, CustomError::TooManyPermissionMembers);
+    Ok(members)
+}
+
+/// Whether an existing group account lists `member`.
+/// Layout: discriminator (1) | bump (1) | id (32) | members (u32 len + 32 each)
+pub fn group_has_member(group: &AccountInfo, member: &Pubkey) -> Result<bool> {
+    require_keys_eq!(*group.owner, PERMISSION_PROGRAM_ID, CustomError::NotAGroupMember);
+
+    let data = group.try_borrow_data()?;
+    let len_bytes: [u8; 4] = data
+        .get(34..38)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(CustomError::NotAGroupMember)?;
+    let count = u32::from_le_bytes(len_bytes) as usize;
+
+    Ok((0..count).any(|i| {
+        let start = 38 + 32 * i;
+        data.get(start..start + 32) == Some(member.as_ref())
+    }))
+}
+
+/// Creates the group and the permission for a freshly placed bet.
+/// `bet_seeds` must include the bump: the UserBet PDA signs as the delegated account.
+#[allow(clippy::too_many_arguments)]
+pub fn create_bet_permission<'info>(
+    permission_program: &AccountInfo<'info>,
+    group: &AccountInfo<'info>,
+    permission: &AccountInfo<'info>,
+    user_bet: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    members: Vec<Pubkey>,
+    bet_seeds: &[&[u8]],
+) -> Result<()> {
+    // Create Group (ID = UserBet Key)
+    CreateGroupCpiBuilder::new(permission_program)
+        .group(group)
+        .id(user_bet.key())
+        .members(members)
+        .payer(payer)
+        .system_program(system_program)
+        .invoke()?;
+
+    // Create Permission (UserBet is the delegated account)
+    CreatePermissionCpiBuilder::new(permission_program)
+        .permission(permission)
+        .delegated_account(user_bet)
+        .group(group)
+        .payer(payer)
+        .system_program(system_program)
+        .invoke_signed(&[bet_seeds])?;
+
+    Ok(())
+}
+
+// ------------------------------------------------------------------
+// UPDATE PERMISSIONS INSTRUCTION
+// ------------------------------------------------------------------
 
 #[derive(Accounts)]
-pub struct SetupBetPermissions<'info> {
+#[instruction(request_id: String, group_id: Pubkey)]
+pub struct UpdateBetPermissions<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// The bet account we want to protect inside the TEE
     #[account(
-        mut, 
-        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        seeds = [
+            SEED_BET,
+            pool.key().as_ref(),
+            user.key().as_ref(),
+            request_id.as_bytes()
+        ],
+        bump = user_bet.bump,
+        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized,
     )]
     pub user_bet: Account<'info, UserBet>,
 
-    /// The Permission Group PDA (derived from seeds)
-    /// CHECK: Checked via CPI to MagicBlock program
-    #[account(mut)]
+    /// The new Permission Group PDA
+    /// CHECK: Seeds verification, created via CPI to MagicBlock program
+    #[account(
+        mut,
+        seeds = [SEED_PERMISSION_GROUP, group_id.as_ref()],
+        seeds::program = permission_program.key(),
+        bump
+    )]
     pub group: UncheckedAccount<'info>,
 
-    /// The Permission PDA
-    /// CHECK: Checked via CPI to MagicBlock program
-    #[account(mut)]
+    /// CHECK: Seeds verification, updated via CPI to MagicBlock program
+    #[account(
+        mut,
+        seeds = [SEED_PERMISSION, user_bet.key().as_ref()],
+        seeds::program = permission_program.key(),
+        bump
+    )]
     pub permission: UncheckedAccount<'info>,
 
-    /// The MagicBlock Access Control Program
-    /// CHECK: Checked via CPI
+    /// CHECK: Address verification
+    #[account(address = PERMISSION_PROGRAM_ID)]
     pub permission_program: UncheckedAccount<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn setup_bet_permissions(ctx: Context<SetupBetPermissions>) -> Result<()> {
-    // 1. Define references for clarity
-    let permission_program = &ctx.accounts.permission_program;
-    let group = &ctx.accounts.group;
-    let user = &ctx.accounts.user;
-    let system_program = &ctx.accounts.system_program;
-    let user_bet = &ctx.accounts.user_bet;
-    let permission = &ctx.accounts.permission;
-
-    // 2. Create the Group
-    // We use the UserBet key as the ID for the group
-    let group_id = user_bet.key();
-    
-    // FIX: Use CpiBuilder pattern
-    CreateGroupCpiBuilder::new(permission_program.to_account_info().as_ref())
-        .group(group.to_account_info().as_ref())
-        .id(group_id)
-        .payer(user.to_account_info().as_ref())
-        .system_program(system_program.to_account_info().as_ref())
-        .invoke()?;
+/// Replaces the read-access group of a bet. `members` is the full list of
+/// extra readers (watchers, settlement keepers): adding or removing a member
+/// is done by passing the new list under a fresh `group_id`. If `group_id`
+/// already exists it is reused as-is (`members` is ignored) and must include
+/// the bet owner.
+pub fn update_bet_permissions(
+    ctx: Context<UpdateBetPermissions>,
+    request_id: String,
+    group_id: Pubkey,
+    members: Vec<Pubkey>,
+) -> Result<()> {
+    require!(!ctx.accounts.permission.data_is_empty(), CustomError::PermissionNotInitialized);
 
-    CreatePermissionCpiBuilder::new(permission_program.to_account_info().as_ref())
-        .permission(permission.to_account_info().as_ref())
-        .group(group.to_account_info().as_ref())
-        .delegated_account(user_bet.to_account_info().as_ref()) // SDK uses 'delegated_account'
-        .payer(user.to_account_info().as_ref())
-        .system_program(system_program.to_account_info().as_ref())
-        .invoke()?;
+    let permission_program = ctx.accounts.permission_program.to_account_info();
+    let group = ctx.accounts.group.to_account_info();
+    let user = ctx.accounts.user.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let user_bet = ctx.accounts.user_bet.to_account_info();
+    let permission = ctx.accounts.permission.to_account_info();
+
+    if group.data_is_empty() {
+        let members = build_bet_members(ctx.accounts.user.key(), &members)?;
+
+        CreateGroupCpiBuilder::new(&permission_program)
+            .group(&group)
+            .id(group_id)
+            .members(members)
+            .payer(&user)
+            .system_program(&system_program)
+            .invoke()?;
+    } else {
+        require!(group_has_member(&group, &ctx.accounts.user.key())?, CustomError::NotAGroupMember);
+    }
 
-    msg!("Permissions initialized for Bet: {}", ctx.accounts.user_bet.key());
+    let pool_key = ctx.accounts.pool.key();
+    let user_key = ctx.accounts.user.key();
+    let bump = ctx.accounts.user_bet.bump;
+    let seeds = &[
+        SEED_BET,
+        pool_key.as_ref(),
+        user_key.as_ref(),
+        request_id.as_bytes(),
+        &[bump],
+    ];
+
+    UpdatePermissionCpiBuilder::new(&permission_program)
+        .permission(&permission)
+        .delegated_account(&user_bet)
+        .group(&group)
+        .invoke_signed(&[seeds])?;
+
+    msg!("Bet {} permission group set to {}", user_bet.key(), group.key());
+
+    emit!(BetPermissionsUpdated {
+        bet_address: user_bet.key(),
+        user: user.key(),
+        group: group.key(),
+    });
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::state::{Pool, UserBet, BetStatus};
-use crate::constants::{SEED_POOL};
+use crate::state::{BetStatus, DistributionMode, Leaderboard, Pool, Season, UserBet, UserProfile, UserSeasonEntry};
+use crate::constants::{SEED_LEADERBOARD, SEED_POOL, SEED_PROFILE, SEED_SEASON, SEED_SEASON_ENTRY};
 use crate::errors::CustomError;
+use crate::events::BetWeighted;
+use crate::utils::{mul_div, SafeMath};
 use crate::utils::fixed_math::{
-    calculate_accuracy_score, 
+    calculate_curve_accuracy_score,
     calculate_time_bonus, 
     calculate_conviction_bonus, 
     calculate_parimutuel_weight,
+    calculate_season_points,
+    calculate_streak_bonus,
+    MATH_PRECISION,
 };
 
 #[derive(Accounts)]
@@ -24,29 +29,65 @@ pub struct CalculatePoolOutcome<'info> {
     )]
     pub pool: Account<'info, Pool>,
 
+    /// CHECK: Owner and data checked via UserBet::load_undelegated
+    #[account(mut)]
+    pub user_bet: UncheckedAccount<'info>,
+
+    /// Required for tiered pools
+    #[account(
+        mut,
+        seeds = [SEED_LEADERBOARD, pool.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
+    // --- SEASON (required for pools linked to a season) ---
     #[account(
         mut,
-        constraint = user_bet.owner == bet_owner.key() @ CustomError::Unauthorized,
-        constraint = user_bet.status == BetStatus::Active @ CustomError::AlreadySettled,
-        constraint = user_bet.is_revealed @ CustomError::BetNotRevealed
+        seeds = [SEED_SEASON, season.id.to_le_bytes().as_ref()],
+        bump = season.bump
     )]
-    pub user_bet: Account<'info, UserBet>,
+    pub season: Option<Box<Account<'info, Season>>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserSeasonEntry::LEN,
+        seeds = [SEED_SEASON_ENTRY, pool.season.unwrap_or_default().as_ref(), bet_owner.key().as_ref()],
+        bump
+    )]
+    pub season_entry: Option<Box<Account<'info, UserSeasonEntry>>>,
+
+    /// Required for pools in a series
+    #[account(
+        mut,
+        seeds = [SEED_PROFILE, bet_owner.key().as_ref()],
+        bump = bettor_profile.bump
+    )]
+    pub bettor_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn calculate_pool_outcome(ctx: Context<CalculatePoolOutcome>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
-    let bet = &mut ctx.accounts.user_bet;
+    let mut bet = UserBet::load_undelegated(&ctx.accounts.user_bet)?;
 
+    require_keys_eq!(bet.owner, ctx.accounts.bet_owner.key(), CustomError::Unauthorized);
+    require_keys_eq!(bet.pool, pool.key(), CustomError::MarketMismatch);
+    require!(bet.status == BetStatus::Active, CustomError::AlreadySettled);
+    require!(bet.is_revealed, CustomError::BetNotRevealed);
     require!(pool.is_resolved, CustomError::SettlementTooEarly);
     require!(!bet.is_weight_added, CustomError::AlreadySettled);
 
     let user_prediction = bet.prediction_target;
     let result = pool.final_outcome;
 
-    let accuracy_score = calculate_accuracy_score(
+    let accuracy_score = calculate_curve_accuracy_score(
+        pool.accuracy_curve,
         user_prediction,
         result,
-        pool.max_accuracy_buffer
+        pool.effective_accuracy_buffer(result)?
     )?;
 
     let time_bonus = calculate_time_bonus(
@@ -56,20 +97,74 @@ pub fn calculate_pool_outcome(ctx: Context<CalculatePoolOutcome>) -> Result<()>
     )?;
     let conviction_bonus = calculate_conviction_bonus(bet.update_count);
 
-    let weight = calculate_parimutuel_weight(
+    let base_weight = calculate_parimutuel_weight(
         bet.deposit,
         accuracy_score,
         time_bonus,
         conviction_bonus
     )?;
 
-    pool.total_weight = pool.total_weight.checked_add(weight).unwrap();
+    // The streak snapshot from place_bet boosts this bet, whatever order the
+    // rounds are calculated in; the result feeds the streak of later rounds
+    let streak_bonus = if pool.series_id != 0 {
+        let profile = ctx.accounts.bettor_profile.as_mut().ok_or(CustomError::ProfileRequired)?;
+        profile.record_series_result(pool.series_id, pool.series_round, accuracy_score > 0)?;
+        calculate_streak_bonus(bet.series_streak, pool.max_streak_bonus_bps)?
+    } else {
+        MATH_PRECISION as u64
+    };
+    let weight = mul_div(base_weight, streak_bonus as u128, MATH_PRECISION)?;
+
+    pool.total_weight = pool.total_weight.safe_add(weight)?;
+    pool.record_calculated_bet(bet.deposit, user_prediction.abs_diff(result))?;
+
+    if pool.distribution == DistributionMode::Tiered {
+        let leaderboard = ctx.accounts.leaderboard.as_mut().ok_or(CustomError::LeaderboardRequired)?;
+        leaderboard.insert(ctx.accounts.user_bet.key(), weight);
+    }
+    if let Some(season_key) = pool.season {
+        let season = ctx.accounts.season.as_mut().ok_or(CustomError::SeasonRequired)?;
+        let entry = ctx.accounts.season_entry.as_mut().ok_or(CustomError::SeasonRequired)?;
+        require_keys_eq!(season.key(), season_key, CustomError::SeasonMismatch);
+
+        if season.scores_pool(pool) {
+            if entry.user == Pubkey::default() {
+                entry.season = season_key;
+                entry.user = bet.owner;
+                entry.bump = ctx.bumps.season_entry.ok_or(CustomError::SeasonRequired)?;
+            }
+            entry.add(calculate_season_points(accuracy_score), weight)?;
+            season.record_points(entry.user, entry.points);
+        }
+    }
+
+    if !bet.reveal_counted {
+        pool.stats.revealed_count = pool.stats.revealed_count.safe_add(1)?;
+        bet.reveal_counted = true;
+    }
+    pool.stats.calculated_count = pool.stats.calculated_count.safe_add(1)?;
+    if weight > 0 {
+        pool.stats.weighted_count = pool.stats.weighted_count.safe_add(1)?;
+    }
     
     bet.calculated_weight = weight;
     bet.is_weight_added = true;
     bet.status = BetStatus::Calculated;
+    bet.store(&ctx.accounts.user_bet)?;
 
     msg!("Calculated Parimutuel for User: {}", ctx.accounts.bet_owner.key());
 
+    emit!(BetWeighted {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: bet.owner,
+        pool: pool.key(),
+        accuracy_score,
+        time_bonus,
+        conviction_bonus,
+        streak_bonus,
+        weight,
+        pool_total_weight: pool.total_weight,
+    });
+
     Ok(())
 }
\ No newline at end of file
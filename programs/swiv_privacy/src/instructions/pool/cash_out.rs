@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{BetStatus, GlobalConfig, Pool, UserBet, UserPoolPosition, UserProfile};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, SEED_POSITION, SEED_PROFILE, CASH_OUT_PENALTY_MIN_BPS, CASH_OUT_PENALTY_MAX_BPS};
+use crate::errors::CustomError;
+use crate::events::BetCashedOut;
+use crate::utils::{apply_bps, calculate_cash_out_penalty_bps, withdraw_from_vault, SafeMath};
+use super::stake_vault::*;
+
+#[derive(Accounts)]
+pub struct CashOut<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = !global_config.paused @ CustomError::Paused
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized,
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch,
+        constraint = user_bet.status == BetStatus::Active @ CustomError::AlreadySettled
+    )]
+    pub user_bet: Box<Account<'info, UserBet>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POSITION, pool.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump
+    )]
+    pub user_position: Box<Account<'info, UserPoolPosition>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PROFILE, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    pub vault: StakeVault<'info>,
+}
+
+/// Full exit before `end_time`. The penalty grows towards close and stays in
+/// the vault for the remaining bettors.
+pub fn cash_out(ctx: Context<CashOut>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user_bet = &mut ctx.accounts.user_bet;
+    let clock = Clock::get()?;
+
+    ctx.accounts.vault.check(pool)?;
+
+    require!(clock.unix_timestamp < pool.end_time, CustomError::DurationTooShort);
+
+    let penalty_bps = calculate_cash_out_penalty_bps(
+        pool.start_time,
+        pool.end_time,
+        clock.unix_timestamp,
+        CASH_OUT_PENALTY_MIN_BPS,
+        CASH_OUT_PENALTY_MAX_BPS,
+    )?;
+
+    let deposit = user_bet.deposit;
+    let penalty = apply_bps(deposit, penalty_bps)?;
+    let payout = deposit.safe_sub(penalty)?;
+
+    if payout > 0 {
+        require!(payout <= pool.vault_balance, CustomError::InsufficientLiquidity);
+
+        let recipient = ctx.accounts.vault.recipient(pool, &ctx.accounts.user.to_account_info())?;
+        let vault = ctx.accounts.vault.accounts();
+
+        withdraw_from_vault(pool, &vault, &recipient, payout)?;
+
+        pool.vault_balance = pool.vault_balance.safe_sub(payout)?;
+        pool.stats.total_refunded = pool.stats.total_refunded.safe_add(payout)?;
+    }
+
+    pool.stats.total_withdrawn = pool.stats.total_withdrawn.safe_add(deposit)?;
+    user_bet.status = BetStatus::Exited;
+    ctx.accounts.user_position.close_bet(&mut ctx.accounts.user_profile)?;
+
+    msg!("Bet Cashed Out: {} (penalty {} bps)", payout, penalty_bps);
+
+    emit!(BetCashedOut {
+        bet_address: user_bet.key(),
+        user: user_bet.owner,
+        pool: pool.key(),
+        payout,
+        penalty,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{BetStatus, Pool, UserBet};
+use crate::constants::{SEED_BONUS_VAULT, SEED_POOL};
+use crate::errors::CustomError;
+use crate::events::BonusRewardClaimed;
+use crate::utils::{calculate_weighted_share, withdraw_pool_tokens, SafeMath};
+
+#[derive(Accounts)]
+pub struct ClaimBonusReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// CHECK: Owner and data checked via UserBet::load_undelegated
+    #[account(mut)]
+    pub user_bet: UncheckedAccount<'info>,
+
+    #[account(
+        address = pool.bonus_mint.unwrap_or_default() @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub bonus_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_BONUS_VAULT, pool.key().as_ref()],
+        bump,
+        token::mint = bonus_mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub bonus_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bonus_mint,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Pays the bet's share of the bonus reward, by the same weights as the main
+/// pot. Independent of claim_pool_reward, before or after it, until
+/// `bonus_claim_deadline`.
+pub fn claim_bonus_reward(ctx: Context<ClaimBonusReward>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let mut bet = UserBet::load_undelegated(&ctx.accounts.user_bet)?;
+
+    require_keys_eq!(bet.owner, ctx.accounts.user.key(), CustomError::Unauthorized);
+    require_keys_eq!(bet.pool, pool.key(), CustomError::MarketMismatch);
+    require!(
+        bet.status == BetStatus::Calculated || bet.status == BetStatus::Settled,
+        CustomError::SettlementTooEarly
+    );
+    require!(pool.weight_finalized, CustomError::SettlementTooEarly);
+    require!(Clock::get()?.unix_timestamp <= pool.bonus_claim_deadline(), CustomError::ClaimWindowClosed);
+    require!(!bet.bonus_claimed, CustomError::AlreadySettled);
+
+    let payout_weight = pool.payout_weight(&bet);
+    let amount = if payout_weight > 0 && pool.total_weight > 0 {
+        calculate_weighted_share(payout_weight, pool.total_weight, pool.bonus_total)?
+    } else {
+        0
+    };
+    require!(amount > 0, CustomError::NothingToClaim);
+
+    withdraw_pool_tokens(
+        pool,
+        &ctx.accounts.bonus_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.bonus_vault.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        amount,
+    )?;
+
+    pool.bonus_claimed = pool.bonus_claimed.safe_add(amount)?;
+    bet.bonus_claimed = true;
+    bet.store(&ctx.accounts.user_bet)?;
+
+    emit!(BonusRewardClaimed {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: bet.owner,
+        pool: pool.key(),
+        amount,
+    });
+
+    Ok(())
+}
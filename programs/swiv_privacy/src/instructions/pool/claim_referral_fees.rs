@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Pool, ReferralAccount, StakeAsset};
+use crate::constants::{SEED_POOL, SEED_REFERRAL, SEED_SOL_VAULT};
+use crate::errors::CustomError;
+use crate::events::ReferralFeesClaimed;
+use crate::utils::{withdraw_from_vault, SafeMath, VaultAccounts};
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_REFERRAL, pool.key().as_ref(), referrer.key().as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer @ CustomError::Unauthorized
+    )]
+    pub referral_account: Box<Account<'info, ReferralAccount>>,
+
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(
+        address = pool.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_vault", pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let referral = &mut ctx.accounts.referral_account;
+
+    let amount = referral.claimable();
+    require!(amount > 0, CustomError::NothingToClaim);
+    require!(amount <= pool.referral_fees_owed, CustomError::InsufficientLiquidity);
+
+    let recipient = match pool.asset {
+        StakeAsset::Token => ctx.accounts.referrer_token_account.as_ref().map(|a| a.to_account_info()),
+        StakeAsset::NativeSol => Some(ctx.accounts.referrer.to_account_info()),
+    }
+    .ok_or(CustomError::MissingVaultAccounts)?;
+
+    let vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.pool_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
+
+    withdraw_from_vault(pool, &vault, &recipient, amount)?;
+
+    pool.referral_fees_owed = pool.referral_fees_owed.safe_sub(amount)?;
+    referral.total_claimed = referral.total_claimed.safe_add(amount)?;
+
+    msg!("Referral Fees Claimed: {}", amount);
+
+    emit!(ReferralFeesClaimed {
+        pool: pool.key(),
+        referrer: referral.referrer,
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{Pool, UserBet, BetStatus};
-use crate::constants::{SEED_POOL};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    BetStatus, DistributionMode, Leaderboard, PayoutBasis, Pool, StakeAsset, UserBet, UserPoolPosition, UserProfile
+};
+use crate::constants::{SEED_LEADERBOARD, SEED_POOL, SEED_SOL_VAULT, SEED_POSITION, SEED_PROFILE};
 use crate::errors::CustomError;
+use crate::events::{BetSettled, RewardClaimed};
+use crate::utils::{calculate_weighted_share, withdraw_from_vault, SafeMath, VaultAccounts};
 
 #[derive(Accounts)]
 pub struct ClaimPoolReward<'info> {
@@ -16,67 +20,141 @@ pub struct ClaimPoolReward<'info> {
     )]
     pub pool: Box<Account<'info, Pool>>,
 
+    /// CHECK: Owner and data checked via UserBet::load_undelegated
+    #[account(mut)]
+    pub user_bet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POSITION, pool.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump
+    )]
+    pub user_position: Box<Account<'info, UserPoolPosition>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PROFILE, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// Required for tiered pools
+    #[account(
+        seeds = [SEED_LEADERBOARD, pool.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
+
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(
+        address = pool.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
     #[account(
         mut,
         seeds = [b"pool_vault", pool.key().as_ref()],
         bump,
+        token::mint = mint,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mut,
-        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized,
-        constraint = user_bet.status == BetStatus::Calculated @ CustomError::SettlementTooEarly
+        token::mint = mint,
+        token::token_program = token_program,
     )]
-    pub user_bet: Box<Account<'info, UserBet>>,
+    pub user_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
 
-    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn claim_pool_reward(ctx: Context<ClaimPoolReward>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
-    let bet = &mut ctx.accounts.user_bet;
+    let mut bet = UserBet::load_undelegated(&ctx.accounts.user_bet)?;
     let mut payout_amount: u64 = 0;
 
+    require_keys_eq!(bet.owner, ctx.accounts.user.key(), CustomError::Unauthorized);
+    require_keys_eq!(bet.pool, pool.key(), CustomError::MarketMismatch);
+    require!(bet.status == BetStatus::Calculated, CustomError::SettlementTooEarly);
+
     require!(pool.weight_finalized, CustomError::SettlementTooEarly);
     
-    if bet.calculated_weight > 0 && pool.total_weight > 0 {
-        let total_distributable_pot = pool.locked_for_payouts as u128;
-        
-        payout_amount = bet.calculated_weight
-            .checked_mul(total_distributable_pot).unwrap()
-            .checked_div(pool.total_weight).unwrap() as u64;
+    let payout_weight = pool.payout_weight(&bet);
+    if payout_weight > 0 && pool.total_weight > 0 {
+        payout_amount = if pool.distribution == DistributionMode::Tiered && pool.payout_basis == PayoutBasis::Weight {
+            let leaderboard = ctx.accounts.leaderboard.as_ref().ok_or(CustomError::LeaderboardRequired)?;
+            leaderboard.tiered_payout(
+                &ctx.accounts.user_bet.key(),
+                payout_weight,
+                pool.total_weight,
+                pool.locked_for_payouts,
+            )?
+        } else {
+            calculate_weighted_share(payout_weight, pool.total_weight, pool.locked_for_payouts)?
+        };
     }
 
     if payout_amount > 0 {
         require!(payout_amount <= pool.vault_balance, CustomError::InsufficientLiquidity);
 
-        let name_bytes = pool.name.as_bytes();
-        let bump = pool.bump;
-        let seeds = &[SEED_POOL, name_bytes, &[bump]];
-        let signer = &[&seeds[..]];
-
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.pool_vault.to_account_info(),
-                    to: ctx.accounts.user_token_account.to_account_info(),
-                    authority: pool.to_account_info(),
-                },
-                signer,
-            ),
-            payout_amount,
-        )?;
-
-        pool.vault_balance = pool.vault_balance.checked_sub(payout_amount).unwrap();
+        // Token pools pay the user's token account, native SOL pools the wallet
+        let recipient = match pool.asset {
+            StakeAsset::Token => ctx.accounts.user_token_account.as_ref().map(|a| a.to_account_info()),
+            StakeAsset::NativeSol => Some(ctx.accounts.user.to_account_info()),
+        }
+        .ok_or(CustomError::MissingVaultAccounts)?;
+
+        let vault = VaultAccounts {
+            mint: ctx.accounts.mint.as_deref(),
+            pool_vault: ctx.accounts.pool_vault.as_mut(),
+            token_program: ctx.accounts.token_program.as_ref(),
+            sol_vault: ctx.accounts.sol_vault.as_ref(),
+            system_program: &ctx.accounts.system_program,
+        };
+
+        withdraw_from_vault(pool, &vault, &recipient, payout_amount)?;
+
+        pool.vault_balance = pool.vault_balance.safe_sub(payout_amount)?;
+        pool.stats.total_claimed = pool.stats.total_claimed.safe_add(payout_amount)?;
     }
 
     bet.status = BetStatus::Settled;
+    bet.store(&ctx.accounts.user_bet)?;
+    ctx.accounts.user_position.close_bet(&mut ctx.accounts.user_profile)?;
+
+    if payout_amount > 0 {
+        emit!(RewardClaimed {
+            bet_address: ctx.accounts.user_bet.key(),
+            user: bet.owner,
+            pool: pool.key(),
+            amount: payout_amount,
+        });
+    }
+
+    emit!(BetSettled {
+        bet_address: ctx.accounts.user_bet.key(),
+        user: bet.owner,
+        outcome_price: pool.final_outcome,
+        is_win: payout_amount > 0,
+        payout: payout_amount,
+        refund_amount: 0,
+        referral_fee: bet.referral_fee,
+        forced_by_admin: false,
+    });
     
     Ok(())
-}
\ No newline at end of file
+}
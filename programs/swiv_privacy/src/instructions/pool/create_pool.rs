@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::state::{Pool, GlobalConfig};
-use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    AccuracyBuffer, AccuracyCurve, DistributionMode, PayoutBasis, Pool, PoolStats, GlobalConfig, StakeAsset, ZeroWeightPolicy
+};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, SEED_SOL_VAULT, MERCY_BUFFER_DEFAULT};
 use crate::errors::CustomError;
 use crate::events::PoolCreated;
+use anchor_lang::system_program::{self, Transfer};
+use crate::utils::validate_mint_extensions;
 
 #[derive(Accounts)]
-#[instruction(name: String, start_time: i64, end_time: i64, max_accuracy_buffer: u64, conviction_bonus_bps: u64)]
+#[instruction(name: String, start_time: i64, end_time: i64, accuracy_buffer: Option<AccuracyBuffer>, accuracy_curve: AccuracyCurve, conviction_bonus_bps: u64)]
 pub struct CreatePool<'info> {
     #[account(
         mut,
@@ -22,7 +27,32 @@ pub struct CreatePool<'info> {
         seeds = [SEED_POOL, name.as_bytes()],
         bump
     )]
-    pub pool: Account<'info, Pool>,
+    pub pool: Box<Account<'info, Pool>>,
+
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(mint::token_program = token_program)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"pool_vault", pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -35,14 +65,44 @@ pub fn create_pool(
     name: String,
     start_time: i64,
     end_time: i64,
-    max_accuracy_buffer: u64,
+    accuracy_buffer: Option<AccuracyBuffer>,
+    accuracy_curve: AccuracyCurve,
     conviction_bonus_bps: u64,
 ) -> Result<()> {
     require!(end_time > start_time, CustomError::DurationTooShort);
 
+    let (asset, mint) = match (&ctx.accounts.mint, &ctx.accounts.sol_vault) {
+        (Some(mint), None) => {
+            require!(ctx.accounts.pool_vault.is_some(), CustomError::MissingVaultAccounts);
+            validate_mint_extensions(&mint.to_account_info())?;
+            (StakeAsset::Token, mint.key())
+        }
+        (None, Some(sol_vault)) => {
+            // Keep the vault rent-exempt; the reserve is never part of vault_balance
+            let reserve = Rent::get()?.minimum_balance(0);
+            if sol_vault.lamports() < reserve {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.admin.to_account_info(),
+                            to: sol_vault.to_account_info(),
+                        },
+                    ),
+                    reserve - sol_vault.lamports(),
+                )?;
+            }
+            (StakeAsset::NativeSol, Pubkey::default())
+        }
+        _ => return Err(CustomError::MissingVaultAccounts.into()),
+    };
+
     let pool = &mut ctx.accounts.pool;
     pool.admin = ctx.accounts.admin.key();
     pool.name = name.clone();
+    pool.asset = asset;
+    pool.mint = mint;
+    pool.sol_vault_bump = ctx.bumps.sol_vault.unwrap_or_default();
     pool.start_time = start_time;
     pool.end_time = end_time;
     pool.is_resolved = false;
@@ -50,10 +110,42 @@ pub fn create_pool(
     pool.resolution_ts = 0;
     pool.total_weight = 0;
     pool.weight_finalized = false;
+    pool.is_delegated = false;
     pool.vault_balance = 0;
     pool.locked_for_payouts = 0;
+    pool.referral_fees_owed = 0;
+    pool.sponsored_amount = 0;
+    pool.bonus_mint = None;
+    pool.bonus_total = 0;
+    pool.bonus_claimed = 0;
+    // Default: MERCY_BUFFER_DEFAULT bps of the final outcome
+    let (max_accuracy_buffer, accuracy_buffer_bps) = match accuracy_buffer {
+        Some(AccuracyBuffer::Absolute(buffer)) => (buffer, false),
+        Some(AccuracyBuffer::Bps(buffer)) => (buffer, true),
+        None => (MERCY_BUFFER_DEFAULT, true),
+    };
+    require!(max_accuracy_buffer > 0, CustomError::InvalidAmount);
+
     pool.max_accuracy_buffer = max_accuracy_buffer;
+    pool.accuracy_buffer_bps = accuracy_buffer_bps;
+    pool.accuracy_curve = accuracy_curve;
     pool.conviction_bonus_bps = conviction_bonus_bps;
+    pool.min_bet = 0;
+    pool.max_bet = 0;
+    pool.max_bets_per_user = 0;
+    pool.max_total_deposits = 0;
+    pool.one_bet_per_user = false;
+    pool.distribution = DistributionMode::ProRata;
+    pool.season = None;
+    pool.series_id = 0;
+    pool.series_round = 0;
+    pool.max_streak_bonus_bps = 0;
+    pool.zero_weight_policy = ZeroWeightPolicy::RefundProRata;
+    pool.payout_basis = PayoutBasis::Weight;
+    pool.calculated_deposits = 0;
+    pool.closest_distance = u64::MAX;
+    pool.closest_deposits = 0;
+    pool.stats = PoolStats::default();
     pool.bump = ctx.bumps.pool;
 
     emit!(PoolCreated {
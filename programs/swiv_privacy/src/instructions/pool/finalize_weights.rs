@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{Pool, GlobalConfig};
-use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{DistributionMode, GlobalConfig, Leaderboard, PayoutBasis, Pool, Season, StakeAsset, ZeroWeightPolicy};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_LEADERBOARD, SEED_POOL, SEED_SEASON, SEED_SEASON_VAULT, SEED_SOL_VAULT, SEED_TREASURY_VAULT};
 use crate::errors::CustomError;
+use crate::events::WeightsFinalized;
+use crate::utils::{apply_bps, withdraw_from_vault, SafeMath, VaultAccounts};
 
 #[derive(Accounts)]
 pub struct FinalizeWeights<'info> {
@@ -23,63 +25,214 @@ pub struct FinalizeWeights<'info> {
     )]
     pub pool: Account<'info, Pool>,
 
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(
+        address = pool.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
     #[account(
         mut,
         seeds = [b"pool_vault", pool.key().as_ref()],
         bump,
+        token::mint = mint,
         token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = global_config,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
+
+    // --- ROLLOVER (only for ZeroWeightPolicy::Rollover) ---
+    #[account(mut)]
+    pub successor_pool: Option<Box<Account<'info, Pool>>>,
+
+    /// CHECK: Successor's pool_vault / sol_vault, PDA verified in the handler
+    #[account(mut)]
+    pub successor_vault: Option<UncheckedAccount<'info>>,
+
+    /// Required for tiered pools
+    #[account(
+        mut,
+        seeds = [SEED_LEADERBOARD, pool.key().as_ref()],
+        bump = leaderboard.bump
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub leaderboard: Option<Box<Account<'info, Leaderboard>>>,
 
+    // --- SEASON (required for pools linked to a season) ---
     #[account(
-        mut, 
-        token::authority = global_config.treasury_wallet
+        mut,
+        seeds = [SEED_SEASON, season.id.to_le_bytes().as_ref()],
+        bump = season.bump
     )]
-    pub treasury_wallet: Account<'info, TokenAccount>,
+    pub season: Option<Box<Account<'info, Season>>>,
 
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Season's token / SOL vault, PDA verified in the handler
+    #[account(mut)]
+    pub season_vault: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn finalize_weights(ctx: Context<FinalizeWeights>) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let global_config = &ctx.accounts.global_config;
+    let clock = Clock::get()?;
     
     require!(pool.is_resolved, CustomError::SettlementTooEarly);
     require!(!pool.weight_finalized, CustomError::AlreadySettled);
 
-    let total_pot = pool.vault_balance;
-    let fee_amount = total_pot
-        .checked_mul(global_config.protocol_fee_bps).unwrap()
-        .checked_div(10000).unwrap();
+    // Refunds and rollovers return the pot untouched: only charge the fee
+    // when some bet actually wins
+    let charges_fee = pool.total_weight > 0
+        || pool.zero_weight_policy == ZeroWeightPolicy::ClosestPrediction;
+
+    // Sponsored boosts are paid out in full
+    let fee_amount = if charges_fee {
+        let fee_base = pool.vault_balance.safe_sub(pool.sponsored_amount)?;
+        apply_bps(fee_base, global_config.protocol_fee_bps)?
+    } else {
+        0
+    };
+
+    let vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.pool_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
 
     if fee_amount > 0 {
-        let name_bytes = pool.name.as_bytes();
-        let bump = pool.bump;
-        let seeds = &[SEED_POOL, name_bytes, &[bump]];
-        let signer = &[&seeds[..]];
-
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.pool_vault.to_account_info(),
-                    to: ctx.accounts.treasury_wallet.to_account_info(),
-                    authority: pool.to_account_info(),
-                },
-                signer,
-            ),
-            fee_amount,
-        )?;
-
-        pool.vault_balance = pool.vault_balance.checked_sub(fee_amount).unwrap();
+        let treasury_info = match pool.asset {
+            StakeAsset::Token => ctx.accounts.treasury_vault.as_ref().map(|a| a.to_account_info()),
+            StakeAsset::NativeSol => ctx.accounts.treasury_sol_vault.as_ref().map(|a| a.to_account_info()),
+        }
+        .ok_or(CustomError::MissingVaultAccounts)?;
+
+        // The linked season's cut of the fee goes to its prize pool if the pool
+        // ended in-season, however late it is finalized
+        let season_fee = match pool.season {
+            Some(season_key) => {
+                let season = ctx.accounts.season.as_mut().ok_or(CustomError::SeasonRequired)?;
+                let season_vault = ctx.accounts.season_vault.as_ref().ok_or(CustomError::SeasonRequired)?;
+                require_keys_eq!(season.key(), season_key, CustomError::SeasonMismatch);
+
+                let share_bps = if season.scores_pool(pool) { season.fee_share_bps } else { 0 };
+
+                let (expected_vault, _) = Pubkey::find_program_address(&[SEED_SEASON_VAULT, season_key.as_ref()], ctx.program_id);
+                require_keys_eq!(season_vault.key(), expected_vault, CustomError::SeasonMismatch);
+
+                let season_fee = apply_bps(fee_amount, share_bps)?;
+                if season_fee > 0 {
+                    withdraw_from_vault(pool, &vault, &season_vault.to_account_info(), season_fee)?;
+                    season.prize_pool = season.prize_pool.safe_add(season_fee)?;
+                }
+                season_fee
+            }
+            None => 0,
+        };
+
+        let treasury_fee = fee_amount.safe_sub(season_fee)?;
+        if treasury_fee > 0 {
+            withdraw_from_vault(pool, &vault, &treasury_info, treasury_fee)?;
+        }
+
+        pool.vault_balance = pool.vault_balance.safe_sub(fee_amount)?;
+        pool.stats.fees_collected = pool.stats.fees_collected.safe_add(fee_amount)?;
         msg!("Protocol Fee Deducted: {}", fee_amount);
     }
 
+    // Nobody landed within the buffer: apply the pool's fallback
+    let mut rolled_over = 0;
+    if pool.total_weight == 0 {
+        match pool.zero_weight_policy {
+            ZeroWeightPolicy::RefundProRata => {
+                pool.payout_basis = PayoutBasis::Deposit;
+                pool.total_weight = pool.calculated_deposits as u128;
+            }
+            ZeroWeightPolicy::ClosestPrediction => {
+                pool.payout_basis = PayoutBasis::ClosestDeposit;
+                pool.total_weight = pool.closest_deposits as u128;
+            }
+            ZeroWeightPolicy::Rollover { successor } => {
+                let successor_pool = ctx.accounts.successor_pool.as_mut().ok_or(CustomError::MissingVaultAccounts)?;
+                let successor_vault = ctx.accounts.successor_vault.as_ref().ok_or(CustomError::MissingVaultAccounts)?;
+
+                require_keys_eq!(successor_pool.key(), successor, CustomError::MarketMismatch);
+                require!(
+                    successor_pool.asset == pool.asset && successor_pool.mint == pool.mint,
+                    CustomError::MarketMismatch
+                );
+                require!(!successor_pool.is_resolved, CustomError::AlreadySettled);
+                // The pot must still be in play when it lands
+                require!(clock.unix_timestamp < successor_pool.end_time, CustomError::DurationTooShort);
+
+                let vault_seed: &[u8] = match pool.asset {
+                    StakeAsset::Token => b"pool_vault",
+                    StakeAsset::NativeSol => SEED_SOL_VAULT,
+                };
+                let (expected_vault, _) = Pubkey::find_program_address(&[vault_seed, successor.as_ref()], ctx.program_id);
+                require_keys_eq!(successor_vault.key(), expected_vault, CustomError::MarketMismatch);
+
+                rolled_over = pool.vault_balance;
+                if rolled_over > 0 {
+                    withdraw_from_vault(pool, &vault, &successor_vault.to_account_info(), rolled_over)?;
+
+                    pool.vault_balance = 0;
+                    successor_pool.vault_balance = successor_pool.vault_balance.safe_add(rolled_over)?;
+                }
+                msg!("Pot Rolled Over: {} to {}", rolled_over, successor);
+            }
+        }
+    }
+
+    // Size the tiers now that every bet is ranked
+    if pool.distribution == DistributionMode::Tiered && pool.payout_basis == PayoutBasis::Weight {
+        let leaderboard = ctx.accounts.leaderboard.as_mut().ok_or(CustomError::LeaderboardRequired)?;
+        leaderboard.finalize_tiers(pool.stats.weighted_count)?;
+    }
+
     pool.locked_for_payouts = pool.vault_balance;
 
     pool.weight_finalized = true;
     
     msg!("Parimutuel Weights Finalized. Total Weight: {}", pool.total_weight);
 
+    emit!(WeightsFinalized {
+        pool: pool.key(),
+        total_weight: pool.total_weight,
+        protocol_fee: fee_amount,
+        locked_for_payouts: pool.locked_for_payouts,
+        payout_basis: pool.payout_basis,
+        rolled_over,
+    });
+
     Ok(())
-}
\ No newline at end of file
+}
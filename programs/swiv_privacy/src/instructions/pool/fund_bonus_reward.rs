@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::Pool;
+use crate::constants::{SEED_BONUS_VAULT, SEED_POOL};
+use crate::errors::CustomError;
+use crate::events::BonusRewardFunded;
+use crate::utils::{validate_mint_extensions, SafeMath};
+
+#[derive(Accounts)]
+pub struct FundBonusReward<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Set by the pool admin's first funding; later fundings must use the same mint
+    #[account(mint::token_program = token_program)]
+    pub bonus_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        seeds = [SEED_BONUS_VAULT, pool.key().as_ref()],
+        bump,
+        token::mint = bonus_mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub bonus_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bonus_mint,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a secondary reward, split pro-rata by weight through
+/// claim_bonus_reward. The pool admin picks the mint with the first funding;
+/// after that anyone can top it up until weights are finalized.
+pub fn fund_bonus_reward(ctx: Context<FundBonusReward>, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let bonus_mint = &ctx.accounts.bonus_mint;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(!pool.weight_finalized, CustomError::AlreadySettled);
+
+    match pool.bonus_mint {
+        Some(mint) => require_keys_eq!(mint, bonus_mint.key(), CustomError::MarketMismatch),
+        None => {
+            require_keys_eq!(ctx.accounts.funder.key(), pool.admin, CustomError::Unauthorized);
+            validate_mint_extensions(&bonus_mint.to_account_info())?;
+            pool.bonus_mint = Some(bonus_mint.key());
+        }
+    }
+
+    let bonus_vault = &mut ctx.accounts.bonus_vault;
+    let vault_before = bonus_vault.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                mint: bonus_mint.to_account_info(),
+                to: bonus_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+        bonus_mint.decimals,
+    )?;
+
+    // Credit what the vault actually received (Token-2022 transfer fees)
+    bonus_vault.reload()?;
+    let received = bonus_vault.amount.safe_sub(vault_before)?;
+    pool.bonus_total = pool.bonus_total.safe_add(received)?;
+
+    msg!("Bonus Reward Funded: {} (total {})", received, pool.bonus_total);
+
+    emit!(BonusRewardFunded {
+        pool: pool.key(),
+        funder: ctx.accounts.funder.key(),
+        mint: bonus_mint.key(),
+        amount: received,
+        bonus_total: pool.bonus_total,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::Pool;
+use crate::constants::{SEED_POOL, SEED_SOL_VAULT};
+use crate::errors::CustomError;
+use crate::events::PoolFunded;
+use crate::utils::{deposit_to_vault, SafeMath, VaultAccounts};
+
+#[derive(Accounts)]
+pub struct FundPool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(
+        address = pool.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_vault", pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a sponsor boost to the pot. Open to anyone until weights are
+/// finalized; boosts are paid out to winners without a protocol fee.
+pub fn fund_pool(ctx: Context<FundPool>, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(!pool.weight_finalized, CustomError::AlreadySettled);
+
+    let funder_info = ctx.accounts.funder.to_account_info();
+    let funder_token_info = ctx.accounts.funder_token_account.as_ref().map(|a| a.to_account_info());
+
+    let mut vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.pool_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
+
+    let received = deposit_to_vault(pool, &mut vault, &funder_info, funder_token_info.as_ref(), amount)?;
+
+    pool.vault_balance = pool.vault_balance.safe_add(received)?;
+    pool.sponsored_amount = pool.sponsored_amount.safe_add(received)?;
+
+    msg!("Pool Funded: {} (sponsored total {})", received, pool.sponsored_amount);
+
+    emit!(PoolFunded {
+        pool: pool.key(),
+        funder: ctx.accounts.funder.key(),
+        amount: received,
+        sponsored_amount: pool.sponsored_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, PoolStats, StakeAsset};
+use crate::constants::SEED_POOL;
+
+#[derive(Accounts)]
+pub struct GetPoolSummary<'info> {
+    #[account(
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PoolSummary {
+    pub pool: Pubkey,
+    pub name: String,
+    pub asset: StakeAsset,
+    pub mint: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub is_resolved: bool,
+    pub final_outcome: u64,
+    pub weight_finalized: bool,
+    pub total_weight: u128,
+    pub vault_balance: u64,
+    pub locked_for_payouts: u64,
+    /// Bet, deposit, reveal / calculation and payout counters
+    pub stats: PoolStats,
+}
+
+/// Read-only view, returned through `set_return_data` (simulate the transaction).
+pub fn get_pool_summary(ctx: Context<GetPoolSummary>) -> Result<PoolSummary> {
+    let pool = &ctx.accounts.pool;
+
+    Ok(PoolSummary {
+        pool: pool.key(),
+        name: pool.name.clone(),
+        asset: pool.asset,
+        mint: pool.mint,
+        start_time: pool.start_time,
+        end_time: pool.end_time,
+        is_resolved: pool.is_resolved,
+        final_outcome: pool.final_outcome,
+        weight_finalized: pool.weight_finalized,
+        total_weight: pool.total_weight,
+        vault_balance: pool.vault_balance,
+        locked_for_payouts: pool.locked_for_payouts,
+        stats: pool.stats,
+    })
+}
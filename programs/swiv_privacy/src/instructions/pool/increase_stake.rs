@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::{BetStatus, GlobalConfig, Pool, ReferralAccount, ReferrerTier, StakeAsset, UserBet};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, SEED_REFERRAL, SEED_REFERRER_TIER, SEED_TREASURY_VAULT};
+use crate::errors::CustomError;
+use crate::events::StakeIncreased;
+use crate::utils::{apply_bps, deposit_to_vault, transfer_from_payer, SafeMath};
+use super::stake_vault::*;
+
+#[derive(Accounts)]
+pub struct IncreaseStake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = !global_config.paused @ CustomError::Paused
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized,
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch,
+        constraint = user_bet.status == BetStatus::Active @ CustomError::AlreadySettled
+    )]
+    pub user_bet: Box<Account<'info, UserBet>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump,
+        token::authority = global_config,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump
+    )]
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
+
+    // --- REFERRAL (only for bets placed with a referrer) ---
+    #[account(
+        mut,
+        seeds = [SEED_REFERRAL, pool.key().as_ref(), user_bet.referrer.unwrap_or_default().as_ref()],
+        bump = referral_account.bump
+    )]
+    pub referral_account: Option<Box<Account<'info, ReferralAccount>>>,
+
+    /// CHECK: the referrer's ReferrerTier PDA (see ReferrerTier::share_bps)
+    #[account(
+        seeds = [SEED_REFERRER_TIER, user_bet.referrer.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referrer_tier: Option<UncheckedAccount<'info>>,
+
+    pub vault: StakeVault<'info>,
+}
+
+pub fn increase_stake(ctx: Context<IncreaseStake>, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let global_config = &ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    ctx.accounts.vault.check(pool)?;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(clock.unix_timestamp < pool.end_time, CustomError::DurationTooShort);
+
+    let fee_amount = apply_bps(amount, global_config.protocol_fee_bps)?;
+    let net_deposit = amount.safe_sub(fee_amount)?;
+
+    // Same referral split as place_bet, for the bet's original referrer
+    let referral_fee = match ctx.accounts.user_bet.referrer {
+        Some(_) => {
+            require!(ctx.accounts.referral_account.is_some(), CustomError::InvalidReferrer);
+
+            let share_bps = ReferrerTier::share_bps(
+                ctx.accounts.referrer_tier.as_ref().map(|a| a.as_ref()),
+                global_config.referral_share_bps,
+            )?;
+            apply_bps(fee_amount, share_bps)?
+        }
+        None => 0,
+    };
+    let treasury_fee = fee_amount.safe_sub(referral_fee)?;
+
+    let user_info = ctx.accounts.user.to_account_info();
+    let user_token_info = ctx.accounts.vault.user_token_account.as_ref().map(|a| a.to_account_info());
+    let treasury_info = match pool.asset {
+        StakeAsset::Token => ctx.accounts.treasury_vault.as_ref().map(|a| a.to_account_info()),
+        StakeAsset::NativeSol => ctx.accounts.treasury_sol_vault.as_ref().map(|a| a.to_account_info()),
+    }
+    .ok_or(CustomError::MissingVaultAccounts)?;
+
+    let mut vault = ctx.accounts.vault.accounts();
+
+    let received = deposit_to_vault(pool, &mut vault, &user_info, user_token_info.as_ref(), net_deposit)?;
+
+    if treasury_fee > 0 {
+        transfer_from_payer(pool, &vault, &user_info, user_token_info.as_ref(), &treasury_info, treasury_fee)?;
+    }
+
+    let referral_received = if referral_fee > 0 {
+        deposit_to_vault(pool, &mut vault, &user_info, user_token_info.as_ref(), referral_fee)?
+    } else {
+        0
+    };
+
+    if let Some(referral) = ctx.accounts.referral_account.as_mut() {
+        referral.total_earned = referral.total_earned.safe_add(referral_received)?;
+        pool.referral_fees_owed = pool.referral_fees_owed.safe_add(referral_received)?;
+    }
+
+    pool.vault_balance = pool.vault_balance.safe_add(received)?;
+    pool.stats.total_deposited = pool.stats.total_deposited.safe_add(received)?;
+    pool.stats.fees_collected = pool.stats.fees_collected.safe_add(fee_amount)?;
+
+    // Stake-weighted entry time: the top-up only earns the time bonus left at `now`
+    let user_bet = &mut ctx.accounts.user_bet;
+    let old_deposit = user_bet.deposit as i128;
+    let new_deposit = old_deposit.safe_add(received as i128)?;
+
+    if new_deposit > 0 {
+        let weighted_ts = old_deposit
+            .safe_mul(user_bet.creation_ts as i128)?
+            .safe_add((received as i128).safe_mul(clock.unix_timestamp as i128)?)?
+            .safe_div(new_deposit)?;
+        user_bet.creation_ts = weighted_ts as i64;
+    }
+
+    user_bet.deposit = user_bet.deposit.safe_add(received)?;
+    user_bet.referral_fee = user_bet.referral_fee.safe_add(referral_received)?;
+    pool.check_deposit_limits(user_bet.deposit)?;
+
+    msg!("Stake Increased by {}. New Deposit: {}", received, user_bet.deposit);
+
+    emit!(StakeIncreased {
+        bet_address: user_bet.key(),
+        user: user_bet.owner,
+        pool: pool.key(),
+        amount: received,
+        fee: fee_amount,
+        new_deposit: user_bet.deposit,
+    });
+
+    Ok(())
+}
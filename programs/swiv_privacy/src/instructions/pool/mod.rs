@@ -1,13 +1,45 @@
 pub mod create_pool;
+pub mod set_pool_limits;
+pub mod set_zero_weight_policy;
+pub mod set_payout_tiers;
+pub mod set_pool_series;
 pub mod place_bet;
 pub mod resolve_pool;
 pub mod calculate_outcome;
 pub mod finalize_weights;
 pub mod claim_reward;
+pub mod claim_referral_fees;
+pub mod increase_stake;
+pub mod withdraw_stake;
+pub mod cash_out;
+pub mod release_position;
+pub mod stake_vault;
+pub mod fund_pool;
+pub mod fund_bonus_reward;
+pub mod claim_bonus_reward;
+pub mod reclaim_bonus_reward;
+pub mod get_pool_summary;
+pub mod quote_payout;
 
 pub use create_pool::*;
+pub use set_pool_limits::*;
+pub use set_zero_weight_policy::*;
+pub use set_payout_tiers::*;
+pub use set_pool_series::*;
 pub use place_bet::*;
 pub use resolve_pool::*;
 pub use calculate_outcome::*;
 pub use finalize_weights::*;
-pub use claim_reward::*;
\ No newline at end of file
+pub use claim_reward::*;
+pub use claim_referral_fees::*;
+pub use increase_stake::*;
+pub use withdraw_stake::*;
+pub use cash_out::*;
+pub use release_position::*;
+pub use stake_vault::*;
+pub use fund_pool::*;
+pub use fund_bonus_reward::*;
+pub use claim_bonus_reward::*;
+pub use reclaim_bonus_reward::*;
+pub use get_pool_summary::*;
+pub use quote_payout::*;
\ No newline at end of file
@@ -1,18 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{BetStatus, GlobalConfig, Pool, UserBet};
-use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, SEED_BET};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    BetStatus, GlobalConfig, Pool, ReferralAccount, ReferrerTier, StakeAsset, UserBet, UserPoolPosition, UserProfile
+};
+use crate::constants::{
+    SEED_GLOBAL_CONFIG, SEED_POOL, SEED_BET, SEED_SOL_VAULT, SEED_POSITION, SEED_PROFILE, SEED_REFERRAL, SEED_REFERRER_TIER,
+    SEED_TREASURY_VAULT,
+    SEED_PERMISSION, SEED_PERMISSION_GROUP,
+    PERMISSION_PROGRAM_ID
+};
 use crate::errors::CustomError;
 use crate::events::BetPlaced;
-use ephemeral_rollups_sdk::access_control::{
-    CreateGroupCpiBuilder, CreatePermissionCpiBuilder
-};
+use crate::instructions::permissions::{build_bet_members, create_bet_permission};
+use crate::utils::{apply_bps, deposit_to_vault, transfer_from_payer, SafeMath, VaultAccounts};
 
 #[derive(Accounts)]
 #[instruction(
     amount: u64,
     commitment: [u8; 32], 
-    request_id: String
+    request_id: String,
+    referrer: Option<Pubkey>
 )]
 pub struct PlaceBet<'info> {
     #[account(mut)]
@@ -32,22 +39,56 @@ pub struct PlaceBet<'info> {
     )]
     pub pool: Box<Account<'info, Pool>>,
 
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(
+        address = pool.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
     #[account(
         mut,
         seeds = [b"pool_vault", pool.key().as_ref()],
         bump,
+        token::mint = mint,
         token::authority = pool,
+        token::token_program = token_program,
     )]
-    pub pool_vault: Account<'info, TokenAccount>,
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    #[account(mut)]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = global_config,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SOL_VAULT, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     #[account(
-        mut, 
-        token::authority = global_config.treasury_wallet
+        mut,
+        seeds = [SEED_TREASURY_VAULT, pool.mint.as_ref()],
+        bump
     )]
-    pub treasury_wallet: Box<Account<'info, TokenAccount>>,
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
 
     #[account(
         init,
@@ -58,25 +99,71 @@ pub struct PlaceBet<'info> {
     )]
     pub user_bet: Box<Account<'info, UserBet>>,
 
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoolPosition::LEN,
+        seeds = [SEED_POSITION, pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_position: Box<Account<'info, UserPoolPosition>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserProfile::LEN,
+        seeds = [SEED_PROFILE, user.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // --- REFERRAL (only with a referrer) ---
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralAccount::LEN,
+        seeds = [SEED_REFERRAL, pool.key().as_ref(), referrer.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referral_account: Option<Box<Account<'info, ReferralAccount>>>,
+
+    /// CHECK: the referrer's ReferrerTier PDA, required with a referrer even
+    /// when no override exists (see ReferrerTier::share_bps)
+    #[account(
+        seeds = [SEED_REFERRER_TIER, referrer.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referrer_tier: Option<UncheckedAccount<'info>>,
+
     // --- 2. ADD PERMISSION ACCOUNTS ---
     
-    /// The Permission Group PDA (derived from seeds)
-    /// CHECK: Checked via CPI to MagicBlock program
-    #[account(mut)]
+    /// The Permission Group PDA (ID = UserBet key)
+    /// CHECK: Seeds verification, created via CPI to MagicBlock program
+    #[account(
+        mut,
+        seeds = [SEED_PERMISSION_GROUP, user_bet.key().as_ref()],
+        seeds::program = permission_program.key(),
+        bump
+    )]
     pub group: UncheckedAccount<'info>,
 
-    /// The Permission PDA
-    /// CHECK: Checked via CPI to MagicBlock program
-    #[account(mut)]
+    /// The Permission PDA (delegated account = UserBet)
+    /// CHECK: Seeds verification, created via CPI to MagicBlock program
+    #[account(
+        mut,
+        seeds = [SEED_PERMISSION, user_bet.key().as_ref()],
+        seeds::program = permission_program.key(),
+        bump
+    )]
     pub permission: UncheckedAccount<'info>,
 
     /// The MagicBlock Access Control Program
-    /// CHECK: Checked via CPI
+    /// CHECK: Address verification
+    #[account(address = PERMISSION_PROGRAM_ID)]
     pub permission_program: UncheckedAccount<'info>,
     
     // ----------------------------------
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -85,7 +172,8 @@ pub fn place_bet(
     ctx: Context<PlaceBet>,
     amount: u64,
     commitment: [u8; 32], 
-    _request_id: String, 
+    request_id: String, 
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let global_config = &ctx.accounts.global_config;
@@ -93,46 +181,99 @@ pub fn place_bet(
 
     require!(clock.unix_timestamp >= pool.start_time, CustomError::DurationTooShort);
     require!(clock.unix_timestamp < pool.end_time, CustomError::DurationTooShort); 
+    require!(amount > 0, CustomError::BetTooSmall);
 
-    let fee_amount = amount.checked_mul(global_config.protocol_fee_bps).unwrap().checked_div(10000).unwrap();
-    let net_deposit = amount.checked_sub(fee_amount).unwrap();
-
-    // Transfer net_deposit into pool_vault
-    token::transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.user_token_account.to_account_info(),
-                to: ctx.accounts.pool_vault.to_account_info(),
-                authority: ctx.accounts.user.to_account_info(),
-            },
-        ),
-        net_deposit,
-    )?;
+    pool.check_bet_count(ctx.accounts.user_position.bet_count)?;
+
+    let fee_amount = apply_bps(amount, global_config.protocol_fee_bps)?;
+    let net_deposit = amount.safe_sub(fee_amount)?;
+
+    // Referrer's cut of the fee stays in the vault until claimed
+    let referral_fee = match referrer {
+        Some(referrer) => {
+            require_keys_neq!(referrer, ctx.accounts.user.key(), CustomError::InvalidReferrer);
+            require!(ctx.accounts.referral_account.is_some(), CustomError::InvalidReferrer);
+
+            let share_bps = ReferrerTier::share_bps(
+                ctx.accounts.referrer_tier.as_ref().map(|a| a.as_ref()),
+                global_config.referral_share_bps,
+            )?;
+            apply_bps(fee_amount, share_bps)?
+        }
+        None => 0,
+    };
+    let treasury_fee = fee_amount.safe_sub(referral_fee)?;
+
+    let user_info = ctx.accounts.user.to_account_info();
+    let user_token_info = ctx.accounts.user_token_account.as_ref().map(|a| a.to_account_info());
+    let treasury_info = match pool.asset {
+        StakeAsset::Token => ctx.accounts.treasury_vault.as_ref().map(|a| a.to_account_info()),
+        StakeAsset::NativeSol => ctx.accounts.treasury_sol_vault.as_ref().map(|a| a.to_account_info()),
+    }
+    .ok_or(CustomError::MissingVaultAccounts)?;
+
+    let mut vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.pool_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
+
+    // Transfer net_deposit into the vault; credit what it actually received
+    let received = deposit_to_vault(pool, &mut vault, &user_info, user_token_info.as_ref(), net_deposit)?;
 
     // Transfer fee to treasury
-    if fee_amount > 0 {
-        token::transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.user_token_account.to_account_info(),
-                    to: ctx.accounts.treasury_wallet.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            fee_amount,
-        )?;
+    if treasury_fee > 0 {
+        transfer_from_payer(pool, &vault, &user_info, user_token_info.as_ref(), &treasury_info, treasury_fee)?;
+    }
+
+    let referral_received = if referral_fee > 0 {
+        deposit_to_vault(pool, &mut vault, &user_info, user_token_info.as_ref(), referral_fee)?
+    } else {
+        0
+    };
+
+    if let (Some(referrer), Some(referral)) = (referrer, ctx.accounts.referral_account.as_mut()) {
+        if referral.referrer == Pubkey::default() {
+            referral.referrer = referrer;
+            referral.pool = pool.key();
+            referral.bump = ctx.bumps.referral_account.ok_or(CustomError::InvalidReferrer)?;
+        }
+        referral.referred_bets = referral.referred_bets.safe_add(1)?;
+        referral.total_earned = referral.total_earned.safe_add(referral_received)?;
+        pool.referral_fees_owed = pool.referral_fees_owed.safe_add(referral_received)?;
+    }
+
+    pool.vault_balance = pool.vault_balance.safe_add(received)?;
+    pool.stats.total_deposited = pool.stats.total_deposited.safe_add(received)?;
+    pool.check_deposit_limits(received)?;
+
+    let profile = &mut ctx.accounts.user_profile;
+    if profile.owner == Pubkey::default() {
+        profile.owner = ctx.accounts.user.key();
+        profile.created_ts = clock.unix_timestamp;
+        profile.bump = ctx.bumps.user_profile;
     }
 
-    pool.vault_balance = pool.vault_balance.checked_add(net_deposit).unwrap();
+    let position = &mut ctx.accounts.user_position;
+    if position.bet_count == 0 {
+        position.owner = ctx.accounts.user.key();
+        position.pool = pool.key();
+        position.bump = ctx.bumps.user_position;
+        pool.stats.unique_bettors = pool.stats.unique_bettors.safe_add(1)?;
+    }
+    position.open_bet(request_id.clone(), profile)?;
+
+    pool.stats.bet_count = pool.stats.bet_count.safe_add(1)?;
+    pool.stats.fees_collected = pool.stats.fees_collected.safe_add(fee_amount)?;
 
     // 5. Initialize UserBet
     {
         let user_bet = &mut ctx.accounts.user_bet;
         user_bet.owner = ctx.accounts.user.key();
         user_bet.pool = pool.key();
-        user_bet.deposit = net_deposit; 
+        user_bet.deposit = received; 
         user_bet.end_timestamp = pool.end_time;
         
         user_bet.creation_ts = clock.unix_timestamp; 
@@ -147,37 +288,45 @@ pub fn place_bet(
         user_bet.prediction_low = 0; 
         user_bet.prediction_high = 0;
         user_bet.prediction_target = 0;
+
+        user_bet.referrer = referrer;
+        user_bet.referral_fee = referral_received;
+        user_bet.bonus_claimed = false;
+        user_bet.reveal_counted = false;
         
         user_bet.bump = ctx.bumps.user_bet;
+        user_bet.series_streak = if pool.series_id != 0 {
+            ctx.accounts.user_profile.streak_into(pool.series_id, pool.series_round)
+        } else {
+            0
+        };
     }
 
     // --- 3. AUTOMATICALLY SETUP PERMISSIONS ---
-    let permission_program = &ctx.accounts.permission_program;
-    let group = &ctx.accounts.group;
-    let permission = &ctx.accounts.permission;
-    let user = &ctx.accounts.user;
-    let system_program = &ctx.accounts.system_program;
-    
+    let user_key = ctx.accounts.user.key();
+    let pool_key = pool.key();
+    let bet_bump = ctx.bumps.user_bet;
+    let bet_seeds: &[&[u8]] = &[
+        SEED_BET,
+        pool_key.as_ref(),
+        user_key.as_ref(),
+        request_id.as_bytes(),
+        &[bet_bump],
+    ];
+
     let user_bet_info = ctx.accounts.user_bet.to_account_info();
     let group_id = user_bet_info.key();
 
-    // Create Group (ID = UserBet Key)
-    CreateGroupCpiBuilder::new(permission_program.to_account_info().as_ref())
-        .group(group.to_account_info().as_ref())
-        .id(group_id)
-        .members(vec![])
-        .payer(user.to_account_info().as_ref())
-        .system_program(system_program.to_account_info().as_ref())
-        .invoke()?;
-
-    // Create Permission
-    CreatePermissionCpiBuilder::new(permission_program.to_account_info().as_ref())
-        .permission(permission.to_account_info().as_ref())
-        .group(group.to_account_info().as_ref())
-        .delegated_account(user.to_account_info().as_ref()) 
-        .payer(user.to_account_info().as_ref())
-        .system_program(system_program.to_account_info().as_ref())
-        .invoke()?;
+    create_bet_permission(
+        &ctx.accounts.permission_program.to_account_info(),
+        &ctx.accounts.group.to_account_info(),
+        &ctx.accounts.permission.to_account_info(),
+        &user_bet_info,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        build_bet_members(user_key, &[])?,
+        bet_seeds,
+    )?;
 
     msg!("Permissions initialized automatically for Bet: {}", group_id);
 
@@ -185,9 +334,9 @@ pub fn place_bet(
         bet_address: ctx.accounts.user_bet.key(),
         user: ctx.accounts.user.key(),
         pool: pool.key(),
-        amount: net_deposit,
+        amount: received,
         end_timestamp: pool.end_time,
     });
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, Pool, UserProfile};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use crate::errors::CustomError;
+use crate::utils::{apply_bps, mul_div, SafeMath};
+use crate::utils::fixed_math::{
+    calculate_curve_accuracy_score,
+    calculate_time_bonus,
+    calculate_conviction_bonus,
+    calculate_parimutuel_weight,
+    calculate_weighted_share,
+    calculate_streak_bonus,
+    MATH_PRECISION,
+};
+
+#[derive(Accounts)]
+pub struct QuotePayout<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Bettor's profile, to include their series streak
+    pub user_profile: Option<Account<'info, UserProfile>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PayoutQuote {
+    /// Stake after the entry fee
+    pub net_stake: u64,
+    pub accuracy_score: u64,
+    pub time_bonus: u64,
+    pub conviction_bonus: u64,
+    pub streak_bonus: u64,
+    pub weight: u128,
+    /// Current pot including the quoted stake
+    pub estimated_pot: u64,
+    pub estimated_payout: u64,
+    pub share_bps: u64,
+}
+
+/// Read-only estimate for a hypothetical bet, returned through `set_return_data`
+/// (simulate the transaction). Resolved pools quote against `final_outcome`,
+/// open pools against `assumed_outcome`. The share is taken against the
+/// weights calculated so far, so it is optimistic until settlement completes.
+pub fn quote_payout(
+    ctx: Context<QuotePayout>,
+    prediction: u64,
+    stake: u64,
+    entry_ts: i64,
+    assumed_outcome: Option<u64>,
+) -> Result<PayoutQuote> {
+    let pool = &ctx.accounts.pool;
+    let global_config = &ctx.accounts.global_config;
+
+    let outcome = if pool.is_resolved {
+        pool.final_outcome
+    } else {
+        assumed_outcome.ok_or(CustomError::SettlementTooEarly)?
+    };
+
+    let fee_amount = apply_bps(stake, global_config.protocol_fee_bps)?;
+    let net_stake = stake.safe_sub(fee_amount)?;
+
+    let accuracy_score = calculate_curve_accuracy_score(
+        pool.accuracy_curve,
+        prediction,
+        outcome,
+        pool.effective_accuracy_buffer(outcome)?,
+    )?;
+    let time_bonus = calculate_time_bonus(pool.start_time, pool.end_time, entry_ts)?;
+    let conviction_bonus = calculate_conviction_bonus(0);
+
+    let base_weight = calculate_parimutuel_weight(net_stake, accuracy_score, time_bonus, conviction_bonus)?;
+
+    // Same streak place_bet would snapshot now
+    let streak = match &ctx.accounts.user_profile {
+        Some(profile) if pool.series_id != 0 => profile.streak_into(pool.series_id, pool.series_round),
+        _ => 0,
+    };
+    let streak_bonus = calculate_streak_bonus(streak, pool.max_streak_bonus_bps)?;
+    let weight = mul_div(base_weight, streak_bonus as u128, MATH_PRECISION)?;
+
+    let estimated_pot = if pool.weight_finalized {
+        pool.locked_for_payouts
+    } else {
+        pool.vault_balance
+    }
+    .safe_add(net_stake)?;
+
+    let total_weight = pool.total_weight.safe_add(weight)?;
+
+    let (estimated_payout, share_bps) = if weight > 0 {
+        (
+            calculate_weighted_share(weight, total_weight, estimated_pot)?,
+            calculate_weighted_share(weight, total_weight, 10000)?,
+        )
+    } else {
+        (0, 0)
+    };
+
+    Ok(PayoutQuote {
+        net_stake,
+        accuracy_score,
+        time_bonus,
+        conviction_bonus,
+        streak_bonus,
+        weight,
+        estimated_pot,
+        estimated_payout,
+        share_bps,
+    })
+}
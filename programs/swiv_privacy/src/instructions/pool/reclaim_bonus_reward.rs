@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::Pool;
+use crate::constants::{SEED_BONUS_VAULT, SEED_POOL};
+use crate::errors::CustomError;
+use crate::events::BonusRewardReclaimed;
+use crate::utils::{withdraw_pool_tokens, SafeMath};
+
+#[derive(Accounts)]
+pub struct ReclaimBonusReward<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump,
+        has_one = admin @ CustomError::Unauthorized,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        address = pool.bonus_mint.unwrap_or_default() @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub bonus_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_BONUS_VAULT, pool.key().as_ref()],
+        bump,
+        token::mint = bonus_mint,
+        token::authority = pool,
+        token::token_program = token_program,
+    )]
+    pub bonus_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bonus_mint,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns the unclaimed bonus reward to the pool admin: right after
+/// finalization when no bet carries weight, otherwise once
+/// `bonus_claim_deadline` has passed.
+pub fn reclaim_bonus_reward(ctx: Context<ReclaimBonusReward>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(pool.weight_finalized, CustomError::SettlementTooEarly);
+    require!(
+        pool.total_weight == 0 || Clock::get()?.unix_timestamp > pool.bonus_claim_deadline(),
+        CustomError::ClaimWindowOpen
+    );
+
+    let amount = pool.bonus_total.safe_sub(pool.bonus_claimed)?;
+    require!(amount > 0, CustomError::NothingToClaim);
+
+    withdraw_pool_tokens(
+        pool,
+        &ctx.accounts.bonus_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.bonus_vault.to_account_info(),
+        &ctx.accounts.admin_token_account.to_account_info(),
+        amount,
+    )?;
+
+    pool.bonus_claimed = pool.bonus_total;
+
+    emit!(BonusRewardReclaimed {
+        pool: pool.key(),
+        destination: ctx.accounts.admin_token_account.key(),
+        amount,
+    });
+
+    Ok(())
+}
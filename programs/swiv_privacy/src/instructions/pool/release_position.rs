@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, UserPoolPosition, UserProfile};
+use crate::constants::{SEED_POOL, SEED_POSITION, SEED_PROFILE};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct ReleasePosition<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POSITION, pool.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump
+    )]
+    pub user_position: Box<Account<'info, UserPoolPosition>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PROFILE, user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+}
+
+/// Frees the profile's active-pool slot held by bets that will never be
+/// claimed (unrevealed or never calculated) once weights are final.
+pub fn release_position(ctx: Context<ReleasePosition>) -> Result<()> {
+    require!(ctx.accounts.pool.weight_finalized, CustomError::SettlementTooEarly);
+
+    ctx.accounts.user_position.release(&mut ctx.accounts.user_profile);
+
+    msg!("Position Released: {}", ctx.accounts.pool.key());
+
+    Ok(())
+}
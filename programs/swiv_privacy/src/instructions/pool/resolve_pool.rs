@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::{Pool, GlobalConfig};
 use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
 use crate::errors::CustomError;
+use crate::events::PoolResolved;
 
 #[derive(Accounts)]
 pub struct ResolvePool<'info> {
@@ -27,6 +28,8 @@ pub fn resolve_pool(ctx: Context<ResolvePool>, final_outcome: u64) -> Result<()>
     let pool = &mut ctx.accounts.pool;
     
     require!(!pool.is_resolved, CustomError::AlreadySettled);
+    // Resolution happens on the base layer, never inside the rollup
+    require!(!pool.is_delegated, CustomError::PoolDelegated);
     
     let clock = Clock::get()?;
     require!(clock.unix_timestamp >= pool.end_time, CustomError::DurationTooShort);
@@ -39,6 +42,12 @@ pub fn resolve_pool(ctx: Context<ResolvePool>, final_outcome: u64) -> Result<()>
     pool.weight_finalized = false; 
     
     msg!("Pool Resolved. Outcome: {}", final_outcome);
+
+    emit!(PoolResolved {
+        pool: pool.key(),
+        final_outcome,
+        resolution_ts: pool.resolution_ts,
+    });
     
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{DistributionMode, GlobalConfig, Leaderboard, PayoutTier, Pool};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_LEADERBOARD, SEED_POOL};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct SetPayoutTiers<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Leaderboard::LEN,
+        seeds = [SEED_LEADERBOARD, pool.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Switches the pool to tiered payouts (no tiers = back to pro-rata).
+/// Must happen before resolution: ranking starts with the first calculated bet.
+/// Tiers are clamped to the top MAX_LEADERBOARD_ENTRIES bets (see `finalize_tiers`).
+pub fn set_payout_tiers(ctx: Context<SetPayoutTiers>, tiers: Vec<PayoutTier>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(!pool.is_resolved, CustomError::AlreadySettled);
+    Leaderboard::validate_tiers(&tiers)?;
+
+    pool.distribution = if tiers.is_empty() {
+        DistributionMode::ProRata
+    } else {
+        DistributionMode::Tiered
+    };
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.pool = pool.key();
+    leaderboard.tiers = tiers;
+    leaderboard.tier_sizes = Vec::new();
+    leaderboard.tier_weights = Vec::new();
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    msg!("Payout Tiers Updated: {}", leaderboard.tiers.len());
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, GlobalConfig};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use crate::errors::CustomError;
+use crate::events::PoolLimitsUpdated;
+
+#[derive(Accounts)]
+pub struct SetPoolLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Max values of 0 mean "no limit".
+pub fn set_pool_limits(
+    ctx: Context<SetPoolLimits>,
+    min_bet: u64,
+    max_bet: u64,
+    max_bets_per_user: u32,
+    max_total_deposits: u64,
+    one_bet_per_user: bool,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(!pool.is_resolved, CustomError::AlreadySettled);
+    require!(max_bet == 0 || max_bet >= min_bet, CustomError::InvalidAmount);
+
+    pool.min_bet = min_bet;
+    pool.max_bet = max_bet;
+    pool.max_bets_per_user = max_bets_per_user;
+    pool.max_total_deposits = max_total_deposits;
+    pool.one_bet_per_user = one_bet_per_user;
+
+    msg!(
+        "Pool Limits Updated: min {} / max {} / {} bets per user / cap {} / one bet per user: {}",
+        min_bet, max_bet, max_bets_per_user, max_total_deposits, one_bet_per_user
+    );
+
+    emit!(PoolLimitsUpdated {
+        pool: pool.key(),
+        min_bet,
+        max_bet,
+        max_bets_per_user,
+        max_total_deposits,
+        one_bet_per_user,
+    });
+
+    Ok(())
+}
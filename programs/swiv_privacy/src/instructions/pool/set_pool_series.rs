@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, GlobalConfig};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct SetPoolSeries<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Places the pool at `series_round` of a recurring series (series_id 0 = none).
+/// Bettors who scored in the previous rounds get up to `max_streak_bonus_bps`
+/// extra weight.
+pub fn set_pool_series(
+    ctx: Context<SetPoolSeries>,
+    series_id: u64,
+    series_round: u64,
+    max_streak_bonus_bps: u64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(!pool.is_resolved, CustomError::AlreadySettled);
+    require!(max_streak_bonus_bps <= 10000, CustomError::InvalidAmount);
+
+    pool.series_id = series_id;
+    pool.series_round = series_round;
+    pool.max_streak_bonus_bps = max_streak_bonus_bps;
+
+    msg!(
+        "Pool Series Updated: series {} / round {} / max streak bonus {} bps",
+        series_id, series_round, max_streak_bonus_bps
+    );
+
+    Ok(())
+}
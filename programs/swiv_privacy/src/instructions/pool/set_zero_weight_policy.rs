@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{Pool, GlobalConfig, ZeroWeightPolicy};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct SetZeroWeightPolicy<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+}
+
+pub fn set_zero_weight_policy(ctx: Context<SetZeroWeightPolicy>, policy: ZeroWeightPolicy) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(!pool.weight_finalized, CustomError::AlreadySettled);
+    if let ZeroWeightPolicy::Rollover { successor } = policy {
+        require_keys_neq!(successor, pool.key(), CustomError::MarketMismatch);
+    }
+
+    pool.zero_weight_policy = policy;
+
+    msg!("Zero-Weight Policy Updated");
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Pool, StakeAsset};
+use crate::constants::SEED_SOL_VAULT;
+use crate::errors::CustomError;
+use crate::utils::VaultAccounts;
+
+/// Vault accounts of an existing bet's pool, shared by `increase_stake`,
+/// `withdraw_stake` and `cash_out`. A nested struct can't see the parent's
+/// `pool`, so handlers call `check` before moving funds.
+#[derive(Accounts)]
+pub struct StakeVault<'info> {
+    // --- TOKEN POOLS (omit for native SOL) ---
+    #[account(mint::token_program = token_program)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub pool_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL POOLS (omit for tokens) ---
+    #[account(mut)]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeVault<'info> {
+    /// Verifies the passed accounts are `pool`'s mint and vault PDA.
+    pub fn check(&self, pool: &Account<'info, Pool>) -> Result<()> {
+        let pool_key = pool.key();
+
+        if let Some(mint) = &self.mint {
+            require_keys_eq!(mint.key(), pool.mint, CustomError::MarketMismatch);
+        }
+
+        if let Some(pool_vault) = &self.pool_vault {
+            let (expected, _) = Pubkey::find_program_address(&[b"pool_vault", pool_key.as_ref()], &crate::ID);
+            require_keys_eq!(pool_vault.key(), expected, CustomError::MarketMismatch);
+        }
+
+        if let Some(sol_vault) = &self.sol_vault {
+            let expected = Pubkey::create_program_address(
+                &[SEED_SOL_VAULT, pool_key.as_ref(), &[pool.sol_vault_bump]],
+                &crate::ID,
+            )
+            .map_err(|_| CustomError::MarketMismatch)?;
+            require_keys_eq!(sol_vault.key(), expected, CustomError::MarketMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// The account that receives payouts: the user's token account for
+    /// token pools, the wallet itself for native SOL.
+    pub fn recipient(&self, pool: &Pool, user: &AccountInfo<'info>) -> Result<AccountInfo<'info>> {
+        match pool.asset {
+            StakeAsset::Token => self.user_token_account.as_ref().map(|a| a.to_account_info()),
+            StakeAsset::NativeSol => Some(user.clone()),
+        }
+        .ok_or(CustomError::MissingVaultAccounts.into())
+    }
+
+    pub fn accounts(&mut self) -> VaultAccounts<'_, 'info> {
+        VaultAccounts {
+            mint: self.mint.as_deref(),
+            pool_vault: self.pool_vault.as_mut(),
+            token_program: self.token_program.as_ref(),
+            sol_vault: self.sol_vault.as_ref(),
+            system_program: &self.system_program,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{BetStatus, GlobalConfig, Pool, UserBet};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, EARLY_WITHDRAW_PENALTY_BPS};
+use crate::errors::CustomError;
+use crate::events::StakeWithdrawn;
+use crate::utils::{apply_bps, withdraw_from_vault, SafeMath};
+use super::stake_vault::*;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = !global_config.paused @ CustomError::Paused
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        constraint = user_bet.owner == user.key() @ CustomError::Unauthorized,
+        constraint = user_bet.pool == pool.key() @ CustomError::MarketMismatch,
+        constraint = user_bet.status == BetStatus::Active @ CustomError::AlreadySettled
+    )]
+    pub user_bet: Box<Account<'info, UserBet>>,
+
+    pub vault: StakeVault<'info>,
+}
+
+/// Partial withdrawal before `end_time`. The early-exit penalty stays in the
+/// vault and is shared by the remaining bettors.
+pub fn withdraw_stake(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user_bet = &mut ctx.accounts.user_bet;
+    let clock = Clock::get()?;
+
+    ctx.accounts.vault.check(pool)?;
+
+    require!(amount > 0 && amount < user_bet.deposit, CustomError::InvalidAmount);
+    // A full exit goes through cash_out; what stays must still be a valid bet
+    let remaining = user_bet.deposit.safe_sub(amount)?;
+    require!(remaining >= pool.min_bet, CustomError::BetTooSmall);
+    require!(clock.unix_timestamp < pool.end_time, CustomError::DurationTooShort);
+
+    let penalty = apply_bps(amount, EARLY_WITHDRAW_PENALTY_BPS)?;
+    let payout = amount.safe_sub(penalty)?;
+
+    if payout > 0 {
+        require!(payout <= pool.vault_balance, CustomError::InsufficientLiquidity);
+
+        let recipient = ctx.accounts.vault.recipient(pool, &ctx.accounts.user.to_account_info())?;
+        let vault = ctx.accounts.vault.accounts();
+
+        withdraw_from_vault(pool, &vault, &recipient, payout)?;
+
+        pool.vault_balance = pool.vault_balance.safe_sub(payout)?;
+        pool.stats.total_refunded = pool.stats.total_refunded.safe_add(payout)?;
+    }
+
+    user_bet.deposit = remaining;
+    pool.stats.total_withdrawn = pool.stats.total_withdrawn.safe_add(amount)?;
+
+    msg!("Stake Withdrawn: {} (penalty {}). New Deposit: {}", payout, penalty, user_bet.deposit);
+
+    emit!(StakeWithdrawn {
+        bet_address: user_bet.key(),
+        user: user_bet.owner,
+        pool: pool.key(),
+        amount: payout,
+        penalty,
+        new_deposit: user_bet.deposit,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Season, StakeAsset};
+use crate::constants::{SEED_SEASON, SEED_SEASON_VAULT};
+use crate::errors::CustomError;
+use crate::events::SeasonPrizeClaimed;
+use crate::utils::{apply_bps, withdraw_from_season_vault, SafeMath, VaultAccounts};
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SEASON, season.id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    // --- TOKEN SEASONS (omit for native SOL) ---
+    #[account(
+        address = season.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump = season.vault_bump,
+        token::mint = mint,
+        token::authority = season,
+        token::token_program = token_program,
+    )]
+    pub season_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL SEASONS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump = season.vault_bump
+    )]
+    pub season_sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays a top finisher their rank's share of the prize pool between the end
+/// of the season and the claim deadline. The prize pool is final by then;
+/// the first claim snapshots it so every rank is paid from the same total.
+pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let user_key = ctx.accounts.user.key();
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp > season.end_ts, CustomError::SeasonNotEnded);
+    require!(clock.unix_timestamp <= season.claim_deadline(), CustomError::ClaimWindowClosed);
+
+    let rank = season
+        .rank_of(&user_key)
+        .filter(|rank| *rank < season.prize_shares_bps.len())
+        .ok_or(CustomError::NotRanked)?;
+    let rank_bit = 1u16 << rank;
+    require!(season.claimed_mask & rank_bit == 0, CustomError::AlreadySettled);
+
+    if season.claimed_mask == 0 {
+        season.prize_locked = season.prize_pool;
+    }
+
+    let amount = apply_bps(season.prize_locked, season.prize_shares_bps[rank] as u64)?;
+    require!(amount > 0, CustomError::NothingToClaim);
+
+    let recipient = match season.asset {
+        StakeAsset::Token => ctx.accounts.user_token_account.as_ref().map(|a| a.to_account_info()),
+        StakeAsset::NativeSol => Some(ctx.accounts.user.to_account_info()),
+    }
+    .ok_or(CustomError::MissingVaultAccounts)?;
+
+    let vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.season_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.season_sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
+
+    withdraw_from_season_vault(season, &vault, &recipient, amount)?;
+
+    season.claimed_mask |= rank_bit;
+    season.prize_pool = season.prize_pool.safe_sub(amount)?;
+
+    msg!("Season Prize Claimed: {} (rank {})", amount, rank + 1);
+
+    emit!(SeasonPrizeClaimed {
+        season: season.key(),
+        user: user_key,
+        rank: rank as u8,
+        points: season.entries[rank].points,
+        amount,
+    });
+
+    Ok(())
+}
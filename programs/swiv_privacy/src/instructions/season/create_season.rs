@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{GlobalConfig, Season, StakeAsset};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_SEASON, SEED_SEASON_VAULT, MAX_SEASON_PRIZE_RANKS};
+use crate::errors::CustomError;
+use crate::events::SeasonCreated;
+use crate::utils::validate_mint_extensions;
+
+#[derive(Accounts)]
+#[instruction(season_id: u32, start_ts: i64, end_ts: i64, fee_share_bps: u64, prize_shares_bps: Vec<u16>)]
+pub struct CreateSeason<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Season::space(prize_shares_bps.len()),
+        seeds = [SEED_SEASON, season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    // --- TOKEN SEASONS (omit for native SOL) ---
+    #[account(mint::token_program = token_program)]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = season,
+        token::token_program = token_program,
+    )]
+    pub season_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL SEASONS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump
+    )]
+    pub season_sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    season_id: u32,
+    start_ts: i64,
+    end_ts: i64,
+    fee_share_bps: u64,
+    prize_shares_bps: Vec<u16>,
+) -> Result<()> {
+    require!(end_ts > start_ts, CustomError::DurationTooShort);
+    require!(fee_share_bps <= 10000, CustomError::InvalidAmount);
+    require!(
+        !prize_shares_bps.is_empty() && prize_shares_bps.len() <= MAX_SEASON_PRIZE_RANKS,
+        CustomError::InvalidSeasonPrizes
+    );
+    let total_share: u64 = prize_shares_bps.iter().map(|share| *share as u64).sum();
+    require!(total_share <= 10000, CustomError::InvalidSeasonPrizes);
+
+    let (asset, mint, vault_bump) = match (&ctx.accounts.mint, &ctx.accounts.season_sol_vault) {
+        (Some(mint), None) => {
+            require!(ctx.accounts.season_vault.is_some(), CustomError::MissingVaultAccounts);
+            validate_mint_extensions(&mint.to_account_info())?;
+            (StakeAsset::Token, mint.key(), ctx.bumps.season_vault.unwrap_or_default())
+        }
+        (None, Some(sol_vault)) => {
+            // Keep the vault rent-exempt; the reserve is never part of prize_pool
+            let reserve = Rent::get()?.minimum_balance(0);
+            if sol_vault.lamports() < reserve {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.admin.to_account_info(),
+                            to: sol_vault.to_account_info(),
+                        },
+                    ),
+                    reserve - sol_vault.lamports(),
+                )?;
+            }
+            (StakeAsset::NativeSol, Pubkey::default(), ctx.bumps.season_sol_vault.unwrap_or_default())
+        }
+        _ => return Err(CustomError::MissingVaultAccounts.into()),
+    };
+
+    let season = &mut ctx.accounts.season;
+    season.id = season_id;
+    season.asset = asset;
+    season.mint = mint;
+    season.vault_bump = vault_bump;
+    season.start_ts = start_ts;
+    season.end_ts = end_ts;
+    season.fee_share_bps = fee_share_bps;
+    season.prize_shares_bps = prize_shares_bps;
+    season.prize_pool = 0;
+    season.prize_locked = 0;
+    season.claimed_mask = 0;
+    season.entries = Vec::new();
+    season.bump = ctx.bumps.season;
+
+    emit!(SeasonCreated {
+        season: season.key(),
+        season_id,
+        start_ts,
+        end_ts,
+        fee_share_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod create_season;
+pub mod set_pool_season;
+pub mod claim_season_prize;
+pub mod sweep_season;
+
+pub use create_season::*;
+pub use set_pool_season::*;
+pub use claim_season_prize::*;
+pub use sweep_season::*;
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalConfig, Pool, Season};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_POOL, SEED_SEASON};
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct SetPoolSeason<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump,
+        constraint = global_config.admin == admin.key() @ CustomError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL, pool.name.as_bytes()],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// Omit to unlink the pool
+    #[account(
+        seeds = [SEED_SEASON, season.id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Option<Box<Account<'info, Season>>>,
+}
+
+/// Links the pool to a season (or unlinks it). Must happen before any bet
+/// is calculated, so every bettor in the pool is scored the same way.
+pub fn set_pool_season(ctx: Context<SetPoolSeason>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    require!(!pool.is_resolved, CustomError::AlreadySettled);
+
+    pool.season = match &ctx.accounts.season {
+        Some(season) => {
+            // Fees move into the season vault, so the assets must match
+            require!(
+                season.asset == pool.asset && season.mint == pool.mint,
+                CustomError::MarketMismatch
+            );
+            require!(
+                pool.end_time >= season.start_ts && pool.end_time <= season.end_ts,
+                CustomError::SeasonMismatch
+            );
+            Some(season.key())
+        }
+        None => None,
+    };
+
+    msg!("Pool Season Updated: {:?}", pool.season);
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{GlobalConfig, Season, StakeAsset};
+use crate::constants::{SEED_GLOBAL_CONFIG, SEED_SEASON, SEED_SEASON_VAULT, SEED_TREASURY_VAULT};
+use crate::errors::CustomError;
+use crate::events::SeasonSwept;
+use crate::utils::{withdraw_from_season_vault, VaultAccounts};
+
+#[derive(Accounts)]
+pub struct SweepSeason<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_CONFIG],
+        bump
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SEASON, season.id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    // --- TOKEN SEASONS (omit for native SOL) ---
+    #[account(
+        address = season.mint @ CustomError::MarketMismatch,
+        mint::token_program = token_program,
+    )]
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump = season.vault_bump,
+        token::mint = mint,
+        token::authority = season,
+        token::token_program = token_program,
+    )]
+    pub season_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, season.mint.as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = global_config,
+        token::token_program = token_program,
+    )]
+    pub treasury_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // --- NATIVE SOL SEASONS (omit for tokens) ---
+    #[account(
+        mut,
+        seeds = [SEED_SEASON_VAULT, season.key().as_ref()],
+        bump = season.vault_bump
+    )]
+    pub season_sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_VAULT, season.mint.as_ref()],
+        bump
+    )]
+    pub treasury_sol_vault: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Permissionless: after the claim deadline, moves whatever the prize pool
+/// still holds (unclaimed ranks, ranks nobody reached) to the treasury.
+pub fn sweep_season(ctx: Context<SweepSeason>) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp > season.claim_deadline(), CustomError::ClaimWindowOpen);
+
+    let amount = season.prize_pool;
+    require!(amount > 0, CustomError::NothingToClaim);
+
+    let treasury_info = match season.asset {
+        StakeAsset::Token => ctx.accounts.treasury_vault.as_ref().map(|a| a.to_account_info()),
+        StakeAsset::NativeSol => ctx.accounts.treasury_sol_vault.as_ref().map(|a| a.to_account_info()),
+    }
+    .ok_or(CustomError::MissingVaultAccounts)?;
+
+    let vault = VaultAccounts {
+        mint: ctx.accounts.mint.as_deref(),
+        pool_vault: ctx.accounts.season_vault.as_mut(),
+        token_program: ctx.accounts.token_program.as_ref(),
+        sol_vault: ctx.accounts.season_sol_vault.as_ref(),
+        system_program: &ctx.accounts.system_program,
+    };
+
+    withdraw_from_season_vault(season, &vault, &treasury_info, amount)?;
+
+    season.prize_pool = 0;
+
+    msg!("Season Swept: {} to the treasury", amount);
+
+    emit!(SeasonSwept {
+        season: season.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use solana_program::keccak; 
-use crate::state::UserBet; 
+use crate::state::{Pool, UserBet};
 use crate::errors::CustomError;
 use crate::events::BetRevealed;
-use crate::constants::PERMISSION_PROGRAM_ID;
+use crate::constants::{PERMISSION_PROGRAM_ID, SEED_PERMISSION};
+use crate::utils::SafeMath;
 
 #[derive(Accounts)]
 pub struct RevealBet<'info> {
@@ -17,17 +18,17 @@ pub struct RevealBet<'info> {
     )]
     pub user_bet: Account<'info, UserBet>,
 
-    /// CHECK: Seeds verification
+    /// Counts the reveal when the pool is writable here (L1, or a delegated
+    /// pool on the rollup); otherwise calculate_pool_outcome counts it
     #[account(
-        seeds = [b"group", user_bet.key().as_ref()],
-        seeds::program = permission_program.key(),
-        bump
+        mut,
+        address = user_bet.pool @ CustomError::MarketMismatch
     )]
-    pub group: UncheckedAccount<'info>,
+    pub pool: Option<Account<'info, Pool>>,
 
-    /// CHECK: Seeds verification
+    /// CHECK: Seeds verification (delegated account = UserBet)
     #[account(
-        seeds = [b"permission", group.key().as_ref(), user.key().as_ref()],
+        seeds = [SEED_PERMISSION, user_bet.key().as_ref()],
         seeds::program = permission_program.key(),
         bump
     )]
@@ -49,11 +50,11 @@ pub fn reveal_bet(
     let clock = Clock::get()?;
 
     // 1. Verify Permissions
-    require!(!ctx.accounts.permission.data_is_empty(), CustomError::Unauthorized);
+    require!(!ctx.accounts.permission.data_is_empty(), CustomError::PermissionNotInitialized);
 
     // 2. Timing Check (5 min window)
     let max_delay_seconds = 300; 
-    if clock.unix_timestamp > user_bet.creation_ts + max_delay_seconds {
+    if clock.unix_timestamp > user_bet.creation_ts.safe_add(max_delay_seconds)? {
         return Err(CustomError::RevealWindowExpired.into());
     }
 
@@ -77,6 +78,11 @@ pub fn reveal_bet(
     user_bet.prediction_target = prediction_target;
     user_bet.is_revealed = true;
 
+    if let Some(pool) = ctx.accounts.pool.as_mut() {
+        pool.stats.revealed_count = pool.stats.revealed_count.safe_add(1)?;
+        user_bet.reveal_counted = true;
+    }
+
     emit!(BetRevealed {
         bet_address: user_bet.key(),
         decrypted_low: prediction_low,
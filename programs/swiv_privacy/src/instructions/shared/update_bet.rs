@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use crate::state::{UserBet, Pool, BetStatus};
 use crate::errors::CustomError;
 use crate::events::BetUpdated;
-use crate::constants::PERMISSION_PROGRAM_ID;
+use crate::constants::{PERMISSION_PROGRAM_ID, SEED_PERMISSION};
+use crate::utils::SafeMath;
 
 #[derive(Accounts)]
 pub struct UpdateBet<'info> {
@@ -22,17 +23,9 @@ pub struct UpdateBet<'info> {
     )]
     pub pool: Box<Account<'info, Pool>>,
 
-    /// CHECK: Seeds verification for MagicBlock Group
+    /// CHECK: Seeds verification (delegated account = UserBet)
     #[account(
-        seeds = [b"group", user_bet.key().as_ref()],
-        seeds::program = permission_program.key(),
-        bump
-    )]
-    pub group: UncheckedAccount<'info>,
-
-    /// CHECK: Seeds verification for MagicBlock Permission
-    #[account(
-        seeds = [b"permission", group.key().as_ref(), user.key().as_ref()],
+        seeds = [SEED_PERMISSION, user_bet.key().as_ref()],
         seeds::program = permission_program.key(),
         bump
     )]
@@ -53,13 +46,13 @@ pub fn update_bet(
     let clock = Clock::get()?;
 
     // 1. Verify Permission Data exists (User didn't bypass setup)
-    require!(!ctx.accounts.permission.data_is_empty(), CustomError::Unauthorized);
+    require!(!ctx.accounts.permission.data_is_empty(), CustomError::PermissionNotInitialized);
 
     // Timing Check - ensure pool still open
     require!(clock.unix_timestamp < ctx.accounts.pool.end_time, CustomError::DurationTooShort);
 
     user_bet.creation_ts = clock.unix_timestamp;
-    user_bet.update_count = user_bet.update_count.checked_add(1).unwrap();
+    user_bet.update_count = user_bet.update_count.safe_add(1)?;
 
     // Update Predictions
     let old_low = user_bet.prediction_low;
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::ephemeral;
 
 pub mod constants;
 pub mod errors;
@@ -8,9 +9,11 @@ pub mod state;
 pub mod utils;
 
 use instructions::*;
+use state::{AccuracyBuffer, AccuracyCurve, PayoutTier, ZeroWeightPolicy};
 
 declare_id!("3RpaT6ZyhUPzbARNFMvPycvdWBv2ixBe6MiggSAGuBx7");
 
+#[ephemeral]
 #[program]
 pub mod swiv_privacy {
     use super::*;
@@ -28,11 +31,13 @@ pub mod swiv_privacy {
         ctx: Context<UpdateConfig>,
         new_treasury: Option<Pubkey>,
         new_protocol_fee_bps: Option<u64>,
+        new_referral_share_bps: Option<u64>,
     ) -> Result<()> {
         admin::update_config(
             ctx, 
             new_treasury, 
-            new_protocol_fee_bps
+            new_protocol_fee_bps,
+            new_referral_share_bps
         )
     }
 
@@ -44,23 +49,128 @@ pub mod swiv_privacy {
         admin::set_pause(ctx, paused)
     }
 
-   pub fn delegate_bet(ctx: Context<DelegateBet>, request_id: String) -> Result<()> {
-        instructions::delegation::delegate_bet(ctx, request_id)
+    pub fn set_trusted_validators(
+        ctx: Context<SetTrustedValidators>,
+        validators: Vec<Pubkey>,
+    ) -> Result<()> {
+        admin::set_trusted_validators(ctx, validators)
+    }
+
+    pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        admin::migrate_global_config(ctx)
+    }
+
+    pub fn set_referrer_tier(
+        ctx: Context<SetReferrerTier>,
+        referrer: Pubkey,
+        share_bps: u64,
+    ) -> Result<()> {
+        admin::set_referrer_tier(ctx, referrer, share_bps)
+    }
+
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>,
+        mint_key: Pubkey,
+        epoch_limit: u64,
+    ) -> Result<()> {
+        admin::initialize_treasury(ctx, mint_key, epoch_limit)
+    }
+
+    pub fn set_treasury_policy(
+        ctx: Context<SetTreasuryPolicy>,
+        allowed_destinations: Vec<Pubkey>,
+        epoch_limit: u64,
+    ) -> Result<()> {
+        admin::set_treasury_policy(ctx, allowed_destinations, epoch_limit)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        admin::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn delegate_bet(
+        ctx: Context<DelegateBet>,
+        request_id: String,
+        validator: Option<Pubkey>,
+        commit_frequency_ms: Option<u32>,
+    ) -> Result<()> {
+        instructions::delegation::delegate_bet(ctx, request_id, validator, commit_frequency_ms)
     }
 
     pub fn undelegate_bet(ctx: Context<UndelegateBet>, request_id: String) -> Result<()> {
         instructions::delegation::undelegate_bet(ctx, request_id)
     }
+
+    pub fn commit_bet(ctx: Context<CommitBet>, request_id: String) -> Result<()> {
+        instructions::delegation::commit_bet(ctx, request_id)
+    }
+
+    pub fn force_undelegate_after_end(
+        ctx: Context<ForceUndelegateAfterEnd>,
+        request_id: String,
+    ) -> Result<()> {
+        instructions::delegation::force_undelegate_after_end(ctx, request_id)
+    }
+
+    pub fn update_bet_permissions(
+        ctx: Context<UpdateBetPermissions>,
+        request_id: String,
+        group_id: Pubkey,
+        members: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::permissions::update_bet_permissions(ctx, request_id, group_id, members)
+    }
+
+    pub fn delegate_pool(ctx: Context<DelegatePool>, validator: Option<Pubkey>) -> Result<()> {
+        instructions::delegation::delegate_pool(ctx, validator)
+    }
+
+    pub fn commit_pool(ctx: Context<CommitPool>) -> Result<()> {
+        instructions::delegation::commit_pool(ctx)
+    }
+
+    pub fn undelegate_pool(ctx: Context<UndelegatePool>) -> Result<()> {
+        instructions::delegation::undelegate_pool(ctx)
+    }
     // --- POOL (Parimutuel) ---
     pub fn create_pool(
         ctx: Context<CreatePool>,
         name: String,
         start_time: i64,
         end_time: i64,
-        max_accuracy_buffer: u64,
+        accuracy_buffer: Option<AccuracyBuffer>,
+        accuracy_curve: AccuracyCurve,
         conviction_bonus_bps: u64,
     ) -> Result<()> {
-        pool::create_pool(ctx, name, start_time, end_time, max_accuracy_buffer, conviction_bonus_bps)
+        pool::create_pool(ctx, name, start_time, end_time, accuracy_buffer, accuracy_curve, conviction_bonus_bps)
+    }
+
+    pub fn set_pool_limits(
+        ctx: Context<SetPoolLimits>,
+        min_bet: u64,
+        max_bet: u64,
+        max_bets_per_user: u32,
+        max_total_deposits: u64,
+        one_bet_per_user: bool,
+    ) -> Result<()> {
+        pool::set_pool_limits(ctx, min_bet, max_bet, max_bets_per_user, max_total_deposits, one_bet_per_user)
+    }
+
+    pub fn set_zero_weight_policy(ctx: Context<SetZeroWeightPolicy>, policy: ZeroWeightPolicy) -> Result<()> {
+        pool::set_zero_weight_policy(ctx, policy)
+    }
+
+    pub fn set_payout_tiers(ctx: Context<SetPayoutTiers>, tiers: Vec<PayoutTier>) -> Result<()> {
+        pool::set_payout_tiers(ctx, tiers)
+    }
+
+    pub fn set_pool_series(
+        ctx: Context<SetPoolSeries>,
+        series_id: u64,
+        series_round: u64,
+        max_streak_bonus_bps: u64,
+    ) -> Result<()> {
+        pool::set_pool_series(ctx, series_id, series_round, max_streak_bonus_bps)
     }
 
     pub fn place_bet(
@@ -68,8 +178,33 @@ pub mod swiv_privacy {
         amount: u64,
         commitment: [u8; 32], 
         request_id: String,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
-        pool::place_bet(ctx, amount, commitment, request_id)
+        pool::place_bet(ctx, amount, commitment, request_id, referrer)
+    }
+
+    pub fn increase_stake(ctx: Context<IncreaseStake>, amount: u64) -> Result<()> {
+        pool::increase_stake(ctx, amount)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+        pool::withdraw_stake(ctx, amount)
+    }
+
+    pub fn cash_out(ctx: Context<CashOut>) -> Result<()> {
+        pool::cash_out(ctx)
+    }
+
+    pub fn release_position(ctx: Context<ReleasePosition>) -> Result<()> {
+        pool::release_position(ctx)
+    }
+
+    pub fn fund_pool(ctx: Context<FundPool>, amount: u64) -> Result<()> {
+        pool::fund_pool(ctx, amount)
+    }
+
+    pub fn fund_bonus_reward(ctx: Context<FundBonusReward>, amount: u64) -> Result<()> {
+        pool::fund_bonus_reward(ctx, amount)
     }
 
     pub fn resolve_pool(
@@ -91,6 +226,56 @@ pub mod swiv_privacy {
         pool::claim_pool_reward(ctx)
     }
 
+    pub fn claim_bonus_reward(ctx: Context<ClaimBonusReward>) -> Result<()> {
+        pool::claim_bonus_reward(ctx)
+    }
+
+    pub fn reclaim_bonus_reward(ctx: Context<ReclaimBonusReward>) -> Result<()> {
+        pool::reclaim_bonus_reward(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        pool::claim_referral_fees(ctx)
+    }
+
+    pub fn get_pool_summary(ctx: Context<GetPoolSummary>) -> Result<PoolSummary> {
+        pool::get_pool_summary(ctx)
+    }
+
+    pub fn quote_payout(
+        ctx: Context<QuotePayout>,
+        prediction: u64,
+        stake: u64,
+        entry_ts: i64,
+        assumed_outcome: Option<u64>,
+    ) -> Result<PayoutQuote> {
+        pool::quote_payout(ctx, prediction, stake, entry_ts, assumed_outcome)
+    }
+
+    // --- SEASONS ---
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u32,
+        start_ts: i64,
+        end_ts: i64,
+        fee_share_bps: u64,
+        prize_shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        season::create_season(ctx, season_id, start_ts, end_ts, fee_share_bps, prize_shares_bps)
+    }
+
+    pub fn set_pool_season(ctx: Context<SetPoolSeason>) -> Result<()> {
+        season::set_pool_season(ctx)
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        season::claim_season_prize(ctx)
+    }
+
+    pub fn sweep_season(ctx: Context<SweepSeason>) -> Result<()> {
+        season::sweep_season(ctx)
+    }
+
     // --- SHARED ---
     pub fn update_bet(
         ctx: Context<UpdateBet>,
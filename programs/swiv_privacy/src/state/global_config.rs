@@ -1,14 +1,41 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_TRUSTED_VALIDATORS;
+use crate::errors::CustomError;
 
 #[account]
 pub struct GlobalConfig {
     pub admin: Pubkey,
+    /// Kept for account layout; fees accrue in the per-mint Treasury vaults
     pub treasury_wallet: Pubkey,
     pub protocol_fee_bps: u64,
     pub paused: bool,
-    pub total_users: u64,
+    /// Default referrer share of the entry fee (bps of the fee). Takes the
+    /// slot of the original `total_users`, which was never incremented;
+    /// per-pool counts are in PoolStats::unique_bettors
+
+    pub referral_share_bps: u64,
+    /// TEE validators bets and pools may be delegated to (must not be empty to delegate)
+    pub trusted_validators: Vec<Pubkey>,
 }
 
 impl GlobalConfig {
+    /// Original layout, ending with `total_users` (see migrate_global_config)
+    pub const V1_LEN: usize = 8 + 32 + 32 + 8 + 1 + 8;
     pub const BASE_LEN: usize = 8 + 32 + 32 + 8 + 1 + 8;
-}
\ No newline at end of file
+    pub const LEN: usize = Self::BASE_LEN + 4 + 32 * MAX_TRUSTED_VALIDATORS;
+
+    /// Picks the validator to delegate to: the requested one must be trusted,
+    /// otherwise the first trusted validator is used. Delegation is refused
+    /// until the admin has configured at least one.
+    pub fn resolve_validator(&self, requested: Option<Pubkey>) -> Result<Pubkey> {
+        require!(!self.trusted_validators.is_empty(), CustomError::NoTrustedValidators);
+
+        match requested {
+            Some(validator) => {
+                require!(self.trusted_validators.contains(&validator), CustomError::ValidatorNotTrusted);
+                Ok(validator)
+            }
+            None => Ok(self.trusted_validators[0]),
+        }
+    }
+}
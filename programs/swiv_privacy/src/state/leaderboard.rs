@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_LEADERBOARD_ENTRIES, MAX_PAYOUT_TIERS};
+use crate::errors::CustomError;
+use crate::utils::{calculate_weighted_share, apply_bps, SafeMath};
+
+/// Share of the pot split among the top `rank_cutoff_bps` of weighted bets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PayoutTier {
+    pub rank_cutoff_bps: u16,
+    pub share_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LeaderboardEntry {
+    pub bet: Pubkey,
+    pub weight: u128,
+}
+
+/// One per tiered pool: the highest-weight bets, sorted by weight (desc) then
+/// bet key (asc), so the ranking does not depend on calculation order.
+/// Tiers are nested (the top 1% also belongs to the top 10%); each tier is
+/// split by weight among its members and the share left over by the tiers
+/// goes pro-rata by weight to every weighted bet.
+#[account]
+pub struct Leaderboard {
+    pub pool: Pubkey,
+    pub tiers: Vec<PayoutTier>,
+    /// Filled by finalize_weights: members and total weight of each tier
+    pub tier_sizes: Vec<u32>,
+    pub tier_weights: Vec<u128>,
+    pub entries: Vec<LeaderboardEntry>,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const LEN: usize = 8 + 32
+        + 4 + MAX_PAYOUT_TIERS * 4
+        + 4 + MAX_PAYOUT_TIERS * 4
+        + 4 + MAX_PAYOUT_TIERS * 16
+        + 4 + MAX_LEADERBOARD_ENTRIES * (32 + 16)
+        + 1;
+
+    /// Tiers must widen as they go and leave their shares within 100%.
+    pub fn validate_tiers(tiers: &[PayoutTier]) -> Result<()> {
+        require!(tiers.len() <= MAX_PAYOUT_TIERS, CustomError::InvalidPayoutTiers);
+
+        let mut previous_cutoff = 0;
+        let mut total_share: u32 = 0;
+        for tier in tiers {
+            require!(
+                tier.rank_cutoff_bps > previous_cutoff && tier.rank_cutoff_bps <= 10000,
+                CustomError::InvalidPayoutTiers
+            );
+            previous_cutoff = tier.rank_cutoff_bps;
+            total_share += tier.share_bps as u32;
+        }
+        require!(total_share <= 10000, CustomError::InvalidPayoutTiers);
+        Ok(())
+    }
+
+    /// Inserts a weighted bet in rank order; once full, the lowest entry drops out.
+    pub fn insert(&mut self, bet: Pubkey, weight: u128) {
+        if weight == 0 {
+            return;
+        }
+        let ranks_before = |entry: &LeaderboardEntry| {
+            entry.weight > weight || (entry.weight == weight && entry.bet < bet)
+        };
+        if self.entries.len() >= MAX_LEADERBOARD_ENTRIES && self.entries.last().is_some_and(ranks_before) {
+            return;
+        }
+
+        let position = self.entries.partition_point(ranks_before);
+        self.entries.insert(position, LeaderboardEntry { bet, weight });
+        self.entries.truncate(MAX_LEADERBOARD_ENTRIES);
+    }
+
+    /// Sizes each tier against the number of weighted bets (at least one member,
+    /// at most the ranked entries) and sums its weight.
+    ///
+    /// Only the top MAX_LEADERBOARD_ENTRIES bets are ranked: once a cutoff covers
+    /// more bets than that, the tier is clamped to the ranked entries and its
+    /// share goes to fewer, higher-ranked bets than the cutoff suggests.
+    pub fn finalize_tiers(&mut self, weighted_count: u64) -> Result<()> {
+        let ranked = self.entries.len() as u64;
+        let mut sizes = Vec::with_capacity(self.tiers.len());
+        let mut weights = Vec::with_capacity(self.tiers.len());
+
+        for tier in &self.tiers {
+            let cutoff = apply_bps(weighted_count, tier.rank_cutoff_bps as u64)?;
+            let size = cutoff.max(1).min(ranked) as usize;
+
+            let mut tier_weight: u128 = 0;
+            for entry in &self.entries[..size] {
+                tier_weight = tier_weight.safe_add(entry.weight)?;
+            }
+            sizes.push(size as u32);
+            weights.push(tier_weight);
+        }
+
+        self.tier_sizes = sizes;
+        self.tier_weights = weights;
+        Ok(())
+    }
+
+    /// Payout for a bet: its cut of every tier it ranks in, plus its
+    /// pro-rata cut of the share left over by the tiers.
+    pub fn tiered_payout(&self, bet: &Pubkey, weight: u128, total_weight: u128, pot: u64) -> Result<u64> {
+        let rank = self.entries.iter().position(|entry| entry.bet == *bet);
+
+        let mut payout: u64 = 0;
+        let mut tiers_share: u64 = 0;
+        let sized_tiers = self.tiers.iter().zip(&self.tier_sizes).zip(&self.tier_weights);
+        for ((tier, &size), &tier_weight) in sized_tiers {
+            tiers_share += tier.share_bps as u64;
+
+            let in_tier = rank.is_some_and(|rank| rank < size as usize);
+            if in_tier && tier_weight > 0 {
+                let tier_pot = apply_bps(pot, tier.share_bps as u64)?;
+                payout = payout.safe_add(calculate_weighted_share(weight, tier_weight, tier_pot)?)?;
+            }
+        }
+
+        let remainder_pot = apply_bps(pot, 10000 - tiers_share)?;
+        if total_weight > 0 {
+            payout = payout.safe_add(calculate_weighted_share(weight, total_weight, remainder_pot)?)?;
+        }
+
+        Ok(payout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::safe_math::tests::XorShift;
+
+    fn key(n: u64) -> Pubkey {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&n.to_be_bytes());
+        Pubkey::new_from_array(bytes)
+    }
+
+    fn leaderboard(tiers: Vec<PayoutTier>) -> Leaderboard {
+        Leaderboard {
+            pool: Pubkey::default(),
+            tiers,
+            tier_sizes: Vec::new(),
+            tier_weights: Vec::new(),
+            entries: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    fn tier(rank_cutoff_bps: u16, share_bps: u16) -> PayoutTier {
+        PayoutTier { rank_cutoff_bps, share_bps }
+    }
+
+    #[test]
+    fn insert_ranks_by_weight_then_bet_key() {
+        let mut board = leaderboard(Vec::new());
+        board.insert(key(3), 50);
+        board.insert(key(1), 100);
+        board.insert(key(9), 0);
+        board.insert(key(2), 50);
+
+        let ranked: Vec<_> = board.entries.iter().map(|e| (e.bet, e.weight)).collect();
+        assert_eq!(ranked, vec![(key(1), 100), (key(2), 50), (key(3), 50)]);
+    }
+
+    #[test]
+    fn insert_is_order_independent_and_keeps_the_top_entries() {
+        let mut rng = XorShift(0x1ead_b0a2_d000_0046);
+        let bets: Vec<(Pubkey, u128)> = (0..(MAX_LEADERBOARD_ENTRIES as u64 + 50))
+            .map(|n| (key(n), (rng.next_u64() % 20) as u128))
+            .collect();
+
+        let mut forward = leaderboard(Vec::new());
+        let mut backward = leaderboard(Vec::new());
+        for (bet, weight) in &bets {
+            forward.insert(*bet, *weight);
+        }
+        for (bet, weight) in bets.iter().rev() {
+            backward.insert(*bet, *weight);
+        }
+
+        let mut expected: Vec<_> = bets.iter().filter(|(_, w)| *w > 0).copied().collect();
+        expected.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        expected.truncate(MAX_LEADERBOARD_ENTRIES);
+
+        let ranked = |board: &Leaderboard| board.entries.iter().map(|e| (e.bet, e.weight)).collect::<Vec<_>>();
+        assert_eq!(ranked(&forward), expected);
+        assert_eq!(ranked(&backward), expected);
+    }
+
+    #[test]
+    fn finalize_tiers_sizes_and_clamps() {
+        let mut board = leaderboard(vec![tier(100, 3_000), tier(5_000, 2_000), tier(10_000, 1_000)]);
+        for n in 0..10 {
+            board.insert(key(n), (n + 1) as u128);
+        }
+
+        board.finalize_tiers(10).unwrap();
+        // 1% of 10 rounds to 0 but keeps one member
+        assert_eq!(board.tier_sizes, vec![1, 5, 10]);
+        assert_eq!(board.tier_weights, vec![10, 10 + 9 + 8 + 7 + 6, 55]);
+
+        // More weighted bets than ranked entries: tiers clamp to the leaderboard
+        board.finalize_tiers(1_000).unwrap();
+        assert_eq!(board.tier_sizes, vec![10, 10, 10]);
+    }
+
+    #[test]
+    fn tiered_payout_shares_tiers_and_remainder() {
+        let mut board = leaderboard(vec![tier(1_000, 5_000)]);
+        for n in 0..10 {
+            board.insert(key(n), 10);
+        }
+        board.finalize_tiers(10).unwrap();
+
+        // Top bet (key 0 wins the tie): the whole tier plus a tenth of the rest
+        assert_eq!(board.tiered_payout(&key(0), 10, 100, 1_000).unwrap(), 500 + 50);
+        assert_eq!(board.tiered_payout(&key(5), 10, 100, 1_000).unwrap(), 50);
+        // Unranked bets still get the remainder
+        assert_eq!(board.tiered_payout(&key(99), 10, 110, 1_100).unwrap(), 50);
+    }
+
+    #[test]
+    fn tiered_payouts_never_exceed_the_pot() {
+        let mut rng = XorShift(0x0070_7a15_0000_0046);
+        for _ in 0..200 {
+            let cutoffs = [100, 1_000, 2_500, 10_000];
+            let mut shares = [0u16; 4];
+            let mut left = 10_000u64;
+            for share in shares.iter_mut() {
+                *share = (rng.next_u64() % (left + 1)) as u16;
+                left -= *share as u64;
+            }
+            let tiers: Vec<_> = cutoffs.iter().zip(shares).map(|(&c, s)| tier(c, s)).collect();
+            Leaderboard::validate_tiers(&tiers).unwrap();
+            let mut board = leaderboard(tiers);
+
+            let bets: Vec<(Pubkey, u128)> = (0..1 + rng.next_u64() % 150)
+                .map(|n| (key(n), (rng.next_u64() % 1_000_000) as u128 * 1_000_000))
+                .collect();
+            let weighted: Vec<_> = bets.iter().filter(|(_, w)| *w > 0).collect();
+            let total_weight: u128 = weighted.iter().map(|(_, w)| *w).sum();
+            for (bet, weight) in &bets {
+                board.insert(*bet, *weight);
+            }
+            board.finalize_tiers(weighted.len() as u64).unwrap();
+
+            let pot = rng.next_u64() >> 1;
+            let paid: u128 = weighted
+                .iter()
+                .map(|(bet, weight)| board.tiered_payout(bet, *weight, total_weight, pot).unwrap() as u128)
+                .sum();
+            assert!(paid <= pot as u128);
+        }
+    }
+}
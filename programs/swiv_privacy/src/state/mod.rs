@@ -1,7 +1,19 @@
 pub mod global_config;
 pub mod pool;
 pub mod user_bet;
+pub mod user_pool_position;
+pub mod user_profile;
+pub mod referral;
+pub mod leaderboard;
+pub mod season;
+pub mod treasury;
 
 pub use global_config::*;
 pub use pool::*;
-pub use user_bet::*;
\ No newline at end of file
+pub use user_bet::*;
+pub use user_pool_position::*;
+pub use user_profile::*;
+pub use referral::*;
+pub use leaderboard::*;
+pub use season::*;
+pub use treasury::*;
\ No newline at end of file
@@ -1,9 +1,106 @@
 use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+use crate::state::UserBet;
+use crate::constants::BONUS_CLAIM_WINDOW_SECONDS;
+use crate::utils::{apply_bps, SafeMath};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StakeAsset {
+    /// SPL / Token-2022 `mint`, held in the `pool_vault` token account
+    Token,
+    /// Lamports, held in the system-owned `sol_vault` PDA
+    NativeSol,
+}
+
+/// Aggregate counters maintained by the pool instructions, for dashboards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PoolStats {
+    pub bet_count: u64,
+    pub unique_bettors: u64,
+    /// Net of the entry fee
+    pub total_deposited: u64,
+    /// Revealed bets, counted by reveal_bet when the pool is writable there,
+    /// otherwise when calculate_pool_outcome first sees the reveal
+    pub revealed_count: u64,
+    /// Bets processed by calculate_pool_outcome
+    pub calculated_count: u64,
+    /// Bets that ended with a non-zero weight
+    pub weighted_count: u64,
+    pub total_claimed: u64,
+    /// Paid back through withdraw_stake / cash_out
+    pub total_refunded: u64,
+    pub fees_collected: u64,
+    /// Deposits taken back out through withdraw_stake / cash_out, penalties included
+    pub total_withdrawn: u64,
+}
+
+impl PoolStats {
+    pub const LEN: usize = 10 * 8;
+
+    /// Bettor deposits still in the pool; excludes sponsor funding and rollovers.
+    pub fn open_deposits(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_withdrawn)
+    }
+}
+
+/// Shape of the accuracy score inside `max_accuracy_buffer` (see `fixed_math`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AccuracyCurve {
+    /// 1 - e
+    Linear,
+    /// 1 - e^2: forgiving near the target, steep at the edge
+    Quadratic,
+    /// exp(-4 e^2): Gaussian-like decay
+    Exponential,
+    /// Linear on the miss as a percentage of the outcome, buffer in bps
+    Relative,
+}
+
+/// What `finalize_weights` does when no bet earned any weight.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroWeightPolicy {
+    /// Every calculated bet gets the pot back pro-rata by deposit
+    RefundProRata,
+    /// The closest prediction(s) split the pot pro-rata by deposit
+    ClosestPrediction,
+    /// The pot moves to a successor pool with the same asset
+    Rollover { successor: Pubkey },
+}
+
+/// What `claim_pool_reward` pays against, fixed by `finalize_weights`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PayoutBasis {
+    Weight,
+    Deposit,
+    ClosestDeposit,
+}
+
+/// How `claim_pool_reward` splits the pot between weighted bets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DistributionMode {
+    /// Proportional to weight
+    ProRata,
+    /// Ranked tiers from the pool's Leaderboard
+    Tiered,
+}
+
+/// `max_accuracy_buffer` as given at pool creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AccuracyBuffer {
+    /// Distance in outcome units
+    Absolute(u64),
+    /// Basis points of the final outcome, scales with the resolved value
+    Bps(u64),
+}
 
 #[account]
 pub struct Pool {
     pub admin: Pubkey,
     pub name: String,
+    pub asset: StakeAsset,
+    /// Stake mint (legacy SPL or Token-2022), default for NativeSol pools
+    pub mint: Pubkey,
+    pub sol_vault_bump: u8,
 
     pub start_time: i64,
     pub end_time: i64,
@@ -14,16 +111,288 @@ pub struct Pool {
 
     pub total_weight: u128,
     pub weight_finalized: bool,
+    /// Pool accounting is in the ephemeral rollup (delegate_pool / undelegate_pool)
+    pub is_delegated: bool,
 
     pub vault_balance: u64,
     pub locked_for_payouts: u64,
+    /// Referral fees held in the vault for referrers, outside `vault_balance`
+    pub referral_fees_owed: u64,
+    /// Part of `vault_balance` added through fund_pool; no fees are taken on it
+    pub sponsored_amount: u64,
+
+    // --- BONUS REWARD (secondary mint, pro-rata by weight) ---
+    pub bonus_mint: Option<Pubkey>,
+    pub bonus_total: u64,
+    pub bonus_claimed: u64,
 
     pub max_accuracy_buffer: u64,
+    /// Buffer is in bps of `final_outcome` (the Relative curve always is)
+    pub accuracy_buffer_bps: bool,
+    pub accuracy_curve: AccuracyCurve,
     pub conviction_bonus_bps: u64,
 
+    // --- LIMITS (0 = no limit) ---
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub max_bets_per_user: u32,
+    pub max_total_deposits: u64,
+    /// Each user holds a single bet; extra stake goes through increase_stake
+    pub one_bet_per_user: bool,
+
+    pub distribution: DistributionMode,
+    /// Season this pool scores into, if any
+    pub season: Option<Pubkey>,
+
+    // --- SERIES (series_id 0 = standalone pool) ---
+    pub series_id: u64,
+    /// Position of this pool in the series; streaks need consecutive rounds
+    pub series_round: u64,
+    /// Cap on the streak bonus; 0 tracks streaks without boosting
+    pub max_streak_bonus_bps: u64,
+
+    // --- ZERO-WEIGHT FALLBACK ---
+    pub zero_weight_policy: ZeroWeightPolicy,
+    pub payout_basis: PayoutBasis,
+    /// Deposits of the bets processed by calculate_pool_outcome
+    pub calculated_deposits: u64,
+    pub closest_distance: u64,
+    pub closest_deposits: u64,
+
+    pub stats: PoolStats,
+
     pub bump: u8,
 }
 
 impl Pool {
-    pub const LEN: usize = 8 + 32 + 4 + 64 + 8 + 8 + 1 + 8 + 16 + 8 + 8 + 8 + 1 + 8;
-}
\ No newline at end of file
+    pub const LEN: usize = 8 + 32 + 4 + 64 + 1 + 32 + 1 + 8 + 8 + 1 + 8 + 16 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 1 + 8 + 8 + 4 + 8 + 1 + 1 + (1 + 32) + (1 + 32) + 8 + 8 + 8 + 8 + (1 + 32) + 8 + 8 + 1 + 1 + 8 + 8 + 8 + PoolStats::LEN;
+
+    /// Buffer to score against `result`, in outcome units (bps for the Relative curve).
+    /// A bps buffer is at least 1 unit, so an exact hit on a small outcome still scores.
+    pub fn effective_accuracy_buffer(&self, result: u64) -> Result<u64> {
+        if self.accuracy_buffer_bps && self.accuracy_curve != AccuracyCurve::Relative {
+            Ok(apply_bps(result, self.max_accuracy_buffer)?.max(1))
+        } else {
+            Ok(self.max_accuracy_buffer)
+        }
+    }
+
+    /// Tracks what the zero-weight fallback needs from a calculated bet.
+    pub fn record_calculated_bet(&mut self, deposit: u64, distance: u64) -> Result<()> {
+        self.calculated_deposits = self.calculated_deposits.safe_add(deposit)?;
+
+        if distance < self.closest_distance {
+            self.closest_distance = distance;
+            self.closest_deposits = deposit;
+        } else if distance == self.closest_distance {
+            self.closest_deposits = self.closest_deposits.safe_add(deposit)?;
+        }
+        Ok(())
+    }
+
+    /// The bet's share of `total_weight` under the finalized payout basis.
+    pub fn payout_weight(&self, bet: &UserBet) -> u128 {
+        match self.payout_basis {
+            PayoutBasis::Weight => bet.calculated_weight,
+            PayoutBasis::Deposit => bet.deposit as u128,
+            PayoutBasis::ClosestDeposit => {
+                if bet.prediction_target.abs_diff(self.final_outcome) == self.closest_distance {
+                    bet.deposit as u128
+                } else {
+                    0
+                }
+            }
+        }
+    }
+
+    /// End of the bonus reward claims; reclaim_bonus_reward returns the rest.
+    pub fn bonus_claim_deadline(&self) -> i64 {
+        self.resolution_ts.saturating_add(BONUS_CLAIM_WINDOW_SECONDS)
+    }
+
+    /// Checks a new bet against the per-user bet count limits.
+    pub fn check_bet_count(&self, bets_placed: u32) -> Result<()> {
+        let max_bets = if self.one_bet_per_user { 1 } else { self.max_bets_per_user };
+        require!(max_bets == 0 || bets_placed < max_bets, CustomError::TooManyBets);
+        Ok(())
+    }
+
+    /// Checks a bet's resulting deposit and the pool's bettor deposits (already
+    /// counted in `stats`) against the pool limits.
+    pub fn check_deposit_limits(&self, bet_deposit: u64) -> Result<()> {
+        require!(bet_deposit > 0 && bet_deposit >= self.min_bet, CustomError::BetTooSmall);
+        require!(self.max_bet == 0 || bet_deposit <= self.max_bet, CustomError::BetTooLarge);
+        // Sponsored and rolled-over funds do not use up the bettors' cap
+        require!(
+            self.max_total_deposits == 0 || self.stats.open_deposits() <= self.max_total_deposits,
+            CustomError::PoolCapReached
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::state::BetStatus;
+
+    pub(crate) fn pool() -> Pool {
+        Pool {
+            admin: Pubkey::default(),
+            name: "test".to_string(),
+            asset: StakeAsset::Token,
+            mint: Pubkey::default(),
+            sol_vault_bump: 0,
+            start_time: 0,
+            end_time: 100,
+            is_resolved: false,
+            final_outcome: 0,
+            resolution_ts: 0,
+            total_weight: 0,
+            weight_finalized: false,
+            is_delegated: false,
+            vault_balance: 0,
+            locked_for_payouts: 0,
+            referral_fees_owed: 0,
+            sponsored_amount: 0,
+            bonus_mint: None,
+            bonus_total: 0,
+            bonus_claimed: 0,
+            max_accuracy_buffer: 100,
+            accuracy_buffer_bps: false,
+            accuracy_curve: AccuracyCurve::Linear,
+            conviction_bonus_bps: 0,
+            min_bet: 0,
+            max_bet: 0,
+            max_bets_per_user: 0,
+            max_total_deposits: 0,
+            one_bet_per_user: false,
+            distribution: DistributionMode::ProRata,
+            season: None,
+            series_id: 0,
+            series_round: 0,
+            max_streak_bonus_bps: 0,
+            zero_weight_policy: ZeroWeightPolicy::RefundProRata,
+            payout_basis: PayoutBasis::Weight,
+            calculated_deposits: 0,
+            closest_distance: u64::MAX,
+            closest_deposits: 0,
+            stats: PoolStats::default(),
+            bump: 0,
+        }
+    }
+
+    pub(crate) fn bet(deposit: u64, prediction_target: u64, calculated_weight: u128) -> UserBet {
+        UserBet {
+            owner: Pubkey::default(),
+            pool: Pubkey::default(),
+            deposit,
+            end_timestamp: 0,
+            creation_ts: 0,
+            update_count: 0,
+            calculated_weight,
+            is_weight_added: calculated_weight > 0,
+            commitment: [0; 32],
+            is_revealed: true,
+            prediction_low: 0,
+            prediction_high: 0,
+            prediction_target,
+            status: BetStatus::Calculated,
+            referrer: None,
+            referral_fee: 0,
+            bonus_claimed: false,
+            reveal_counted: true,
+            bump: 0,
+            series_streak: 0,
+        }
+    }
+
+    #[test]
+    fn record_calculated_bet_tracks_the_closest_deposits() {
+        let mut pool = pool();
+        pool.record_calculated_bet(100, 50).unwrap();
+        assert_eq!((pool.closest_distance, pool.closest_deposits), (50, 100));
+
+        // Farther bets only add to the calculated deposits
+        pool.record_calculated_bet(200, 80).unwrap();
+        assert_eq!((pool.closest_distance, pool.closest_deposits), (50, 100));
+
+        // A tie shares the closest spot
+        pool.record_calculated_bet(300, 50).unwrap();
+        assert_eq!((pool.closest_distance, pool.closest_deposits), (50, 400));
+
+        // A closer bet takes it over
+        pool.record_calculated_bet(40, 10).unwrap();
+        assert_eq!((pool.closest_distance, pool.closest_deposits), (10, 40));
+        assert_eq!(pool.calculated_deposits, 640);
+    }
+
+    #[test]
+    fn record_calculated_bet_is_order_independent() {
+        let bets = [(100, 7), (250, 3), (60, 3), (10, 9)];
+        let mut forward = pool();
+        let mut backward = pool();
+        for (deposit, distance) in bets {
+            forward.record_calculated_bet(deposit, distance).unwrap();
+        }
+        for (deposit, distance) in bets.iter().rev() {
+            backward.record_calculated_bet(*deposit, *distance).unwrap();
+        }
+        assert_eq!(
+            (forward.closest_distance, forward.closest_deposits, forward.calculated_deposits),
+            (backward.closest_distance, backward.closest_deposits, backward.calculated_deposits)
+        );
+        assert_eq!(forward.closest_deposits, 310);
+    }
+
+    #[test]
+    fn payout_weight_follows_the_payout_basis() {
+        let mut pool = pool();
+        pool.final_outcome = 1_000;
+        let winner = bet(500, 1_000, 42);
+
+        assert_eq!(pool.payout_weight(&winner), 42);
+
+        pool.payout_basis = PayoutBasis::Deposit;
+        assert_eq!(pool.payout_weight(&winner), 500);
+        assert_eq!(pool.payout_weight(&bet(300, 5_000, 0)), 300);
+    }
+
+    #[test]
+    fn payout_weight_splits_a_tie_at_the_closest_distance() {
+        let mut pool = pool();
+        pool.final_outcome = 1_000;
+        let above = bet(100, 1_020, 0);
+        let below = bet(300, 980, 0);
+        let far = bet(1_000, 1_100, 0);
+        for b in [&above, &below, &far] {
+            pool.record_calculated_bet(b.deposit, b.prediction_target.abs_diff(pool.final_outcome)).unwrap();
+        }
+        pool.payout_basis = PayoutBasis::ClosestDeposit;
+        pool.total_weight = pool.closest_deposits as u128;
+
+        // Both sides of the outcome tie at distance 20
+        assert_eq!(pool.payout_weight(&above), 100);
+        assert_eq!(pool.payout_weight(&below), 300);
+        assert_eq!(pool.payout_weight(&far), 0);
+        assert_eq!(pool.payout_weight(&above) + pool.payout_weight(&below), pool.total_weight);
+    }
+
+    #[test]
+    fn deposit_cap_ignores_sponsor_funding_and_withdrawals() {
+        let mut pool = pool();
+        pool.max_total_deposits = 1_000;
+        // A rollover or sponsorship larger than the cap
+        pool.vault_balance = 5_000;
+        pool.sponsored_amount = 5_000;
+
+        pool.stats.total_deposited = 1_000;
+        pool.check_deposit_limits(1_000).unwrap();
+
+        pool.stats.total_deposited = 1_200;
+        assert!(pool.check_deposit_limits(200).is_err());
+
+        pool.stats.total_withdrawn = 200;
+        pool.check_deposit_limits(200).unwrap();
+    }
+}
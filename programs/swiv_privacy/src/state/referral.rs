@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+
+/// One per (pool, referrer): the referrer's share of entry fees, held in the
+/// pool vault outside `vault_balance` until claimed.
+#[account]
+pub struct ReferralAccount {
+    pub referrer: Pubkey,
+    pub pool: Pubkey,
+    pub referred_bets: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl ReferralAccount {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn claimable(&self) -> u64 {
+        self.total_earned.saturating_sub(self.total_claimed)
+    }
+}
+
+/// Admin override of the fee share for a single referrer.
+#[account]
+pub struct ReferrerTier {
+    pub referrer: Pubkey,
+    /// Share of the entry fee, in bps of the fee
+    pub share_bps: u64,
+    pub bump: u8,
+}
+
+impl ReferrerTier {
+    pub const LEN: usize = 8 + 32 + 8 + 1;
+
+    /// Share for a referred bet, read from the referrer's tier PDA. The PDA is
+    /// always passed so an existing override can't be skipped; while it is
+    /// uninitialized the default share applies.
+    pub fn share_bps(tier: Option<&AccountInfo>, default_bps: u64) -> Result<u64> {
+        let tier = tier.ok_or(CustomError::InvalidReferrer)?;
+        if tier.owner != &crate::ID || tier.data_is_empty() {
+            return Ok(default_bps);
+        }
+        let data = tier.try_borrow_data()?;
+        Ok(ReferrerTier::try_deserialize(&mut &data[..])?.share_bps)
+    }
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_SEASON_ENTRIES, SEASON_CLAIM_WINDOW_SECONDS};
+use crate::state::{Pool, StakeAsset};
+use crate::utils::SafeMath;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SeasonEntry {
+    pub user: Pubkey,
+    pub points: u64,
+}
+
+/// A ranking window across pools. Pools linked to the season add season
+/// points to their bettors' UserSeasonEntry and a share of their settlement
+/// fee to the prize pool, paid out by rank once the season has ended.
+/// Whatever is unclaimed after the claim window goes to the treasury.
+#[account]
+pub struct Season {
+    pub id: u32,
+    pub asset: StakeAsset,
+    /// Prize mint, default for NativeSol seasons
+    pub mint: Pubkey,
+    pub vault_bump: u8,
+
+    pub start_ts: i64,
+    pub end_ts: i64,
+
+    /// Share of a linked pool's settlement fee sent to the prize pool
+    pub fee_share_bps: u64,
+    /// Prize share per final rank (index 0 = first place)
+    pub prize_shares_bps: Vec<u16>,
+    /// Prize funds still in the season vault
+    pub prize_pool: u64,
+    /// Prize pool at the first claim, what the shares are taken from
+    pub prize_locked: u64,
+    pub claimed_mask: u16,
+
+    /// Top users by season points (desc)
+    pub entries: Vec<SeasonEntry>,
+    pub bump: u8,
+}
+
+impl Season {
+    pub fn space(prize_ranks: usize) -> usize {
+        8 + 4 + 1 + 32 + 1 + 8 + 8 + 8 + 4 + 2 * prize_ranks + 8 + 8 + 2
+            + 4 + MAX_SEASON_ENTRIES * (32 + 8)
+            + 1
+    }
+
+    /// Moves `user` to its rank for `points` (their season total).
+    pub fn record_points(&mut self, user: Pubkey, points: u64) {
+        self.entries.retain(|entry| entry.user != user);
+
+        if self.entries.len() >= MAX_SEASON_ENTRIES
+            && self.entries.last().is_some_and(|last| last.points >= points)
+        {
+            return;
+        }
+
+        let position = self.entries.partition_point(|entry| entry.points >= points);
+        self.entries.insert(position, SeasonEntry { user, points });
+        self.entries.truncate(MAX_SEASON_ENTRIES);
+    }
+
+    pub fn is_ranking_open(&self, now: i64) -> bool {
+        self.start_ts <= now && now <= self.end_ts
+    }
+
+    /// Whether `pool` adds points and fees to this season. Decided by when the
+    /// pool ended, not when the keeper calculates or finalizes it.
+    pub fn scores_pool(&self, pool: &Pool) -> bool {
+        self.is_ranking_open(pool.end_time)
+    }
+
+    pub fn claim_deadline(&self) -> i64 {
+        self.end_ts.saturating_add(SEASON_CLAIM_WINDOW_SECONDS)
+    }
+
+    pub fn rank_of(&self, user: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.user == *user)
+    }
+}
+
+
+/// One per (season, user): the user's totals in that season, so overlapping
+/// seasons score independently.
+#[account]
+pub struct UserSeasonEntry {
+    pub season: Pubkey,
+    pub user: Pubkey,
+    pub points: u64,
+    pub weight: u128,
+    pub bump: u8,
+}
+
+impl UserSeasonEntry {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 1;
+
+    pub fn add(&mut self, points: u64, weight: u128) -> Result<()> {
+        self.points = self.points.safe_add(points)?;
+        self.weight = self.weight.safe_add(weight)?;
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn season() -> Season {
+        Season {
+            id: 1,
+            asset: StakeAsset::Token,
+            mint: Pubkey::default(),
+            vault_bump: 0,
+            start_ts: 100,
+            end_ts: 200,
+            fee_share_bps: 5_000,
+            prize_shares_bps: vec![6_000, 4_000],
+            prize_pool: 0,
+            prize_locked: 0,
+            claimed_mask: 0,
+            entries: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn ranking_is_open_only_during_the_season() {
+        let season = season();
+        assert!(!season.is_ranking_open(99));
+        assert!(season.is_ranking_open(100));
+        assert!(season.is_ranking_open(200));
+        assert!(!season.is_ranking_open(201));
+        assert_eq!(season.claim_deadline(), 200 + SEASON_CLAIM_WINDOW_SECONDS);
+    }
+
+    #[test]
+    fn pool_scores_by_its_end_time_not_the_clock() {
+        let season = season();
+        let mut pool = crate::state::pool::tests::pool();
+
+        // Ended in-season, calculated after the season closed
+        pool.end_time = 150;
+        let calculated_at = 250;
+        assert!(!season.is_ranking_open(calculated_at));
+        assert!(season.scores_pool(&pool));
+
+        pool.end_time = 201;
+        assert!(!season.scores_pool(&pool));
+    }
+
+    #[test]
+    fn record_points_reranks_a_user_on_their_new_total() {
+        let mut season = season();
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        season.record_points(alice, 500);
+        season.record_points(bob, 300);
+        assert_eq!(season.rank_of(&alice), Some(0));
+
+        season.record_points(bob, 800);
+        assert_eq!(season.rank_of(&bob), Some(0));
+        assert_eq!(season.rank_of(&alice), Some(1));
+        assert_eq!(season.entries.len(), 2);
+    }
+
+    #[test]
+    fn record_points_keeps_the_top_entries() {
+        let mut season = season();
+        for points in 0..(MAX_SEASON_ENTRIES as u64 + 10) {
+            season.record_points(Pubkey::new_unique(), points);
+        }
+        assert_eq!(season.entries.len(), MAX_SEASON_ENTRIES);
+        assert_eq!(season.entries[0].points, MAX_SEASON_ENTRIES as u64 + 9);
+        assert_eq!(season.entries.last().unwrap().points, 10);
+    }
+}
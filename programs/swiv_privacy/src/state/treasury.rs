@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_TREASURY_DESTINATIONS;
+use crate::errors::CustomError;
+use crate::state::StakeAsset;
+use crate::utils::SafeMath;
+
+/// Withdrawal policy for one fee vault. Fees in `mint` (default for native
+/// SOL) accrue in the `[SEED_TREASURY_VAULT, mint]` vault, a token account
+/// owned by the GlobalConfig PDA or a system-owned SOL PDA.
+#[account]
+pub struct Treasury {
+    pub mint: Pubkey,
+    pub asset: StakeAsset,
+    pub vault_bump: u8,
+
+    /// Token accounts (or wallets for native SOL) withdrawals may go to
+    pub allowed_destinations: Vec<Pubkey>,
+    /// Max withdrawn per Solana epoch (never 0)
+    pub epoch_limit: u64,
+    pub current_epoch: u64,
+    pub withdrawn_in_epoch: u64,
+    pub total_withdrawn: u64,
+
+    pub bump: u8,
+
+    /// Policy queued by set_treasury_policy, applied from `pending_from_epoch`
+    pub pending_destinations: Vec<Pubkey>,
+    pub pending_epoch_limit: u64,
+    pub pending_from_epoch: Option<u64>,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 4 + 32 * MAX_TREASURY_DESTINATIONS + 8 + 8 + 8 + 8 + 1
+        + 4 + 32 * MAX_TREASURY_DESTINATIONS + 8 + 9;
+
+    /// Queues a new allowlist and limit. They take effect from the next epoch,
+    /// so a compromised admin key can't redirect and drain in one go.
+    pub fn queue_policy(&mut self, destinations: Vec<Pubkey>, epoch_limit: u64, epoch: u64) -> Result<u64> {
+        require!(destinations.len() <= MAX_TREASURY_DESTINATIONS, CustomError::TooManyDestinations);
+        require!(epoch_limit > 0, CustomError::InvalidAmount);
+
+        let from_epoch = epoch.safe_add(1)?;
+        self.pending_destinations = destinations;
+        self.pending_epoch_limit = epoch_limit;
+        self.pending_from_epoch = Some(from_epoch);
+        Ok(from_epoch)
+    }
+
+    /// Moves the queued policy in once its epoch has started.
+    pub fn apply_pending_policy(&mut self, epoch: u64) {
+        if self.pending_from_epoch.is_some_and(|from| epoch >= from) {
+            self.allowed_destinations = std::mem::take(&mut self.pending_destinations);
+            self.epoch_limit = self.pending_epoch_limit;
+            self.pending_epoch_limit = 0;
+            self.pending_from_epoch = None;
+        }
+    }
+
+    /// Checks a withdrawal against the allowlist and the epoch limit, and records it.
+    pub fn record_withdrawal(&mut self, destination: &Pubkey, amount: u64, epoch: u64) -> Result<()> {
+        self.apply_pending_policy(epoch);
+        require!(self.allowed_destinations.contains(destination), CustomError::DestinationNotAllowed);
+
+        if epoch != self.current_epoch {
+            self.current_epoch = epoch;
+            self.withdrawn_in_epoch = 0;
+        }
+
+        let withdrawn = self.withdrawn_in_epoch.safe_add(amount)?;
+        require!(
+            withdrawn <= self.epoch_limit,
+            CustomError::TreasuryLimitExceeded
+        );
+
+        self.withdrawn_in_epoch = withdrawn;
+        self.total_withdrawn = self.total_withdrawn.safe_add(amount)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn treasury() -> Treasury {
+        Treasury {
+            mint: Pubkey::default(),
+            asset: StakeAsset::Token,
+            vault_bump: 0,
+            allowed_destinations: Vec::new(),
+            epoch_limit: 1_000,
+            current_epoch: 5,
+            withdrawn_in_epoch: 0,
+            total_withdrawn: 0,
+            bump: 0,
+            pending_destinations: Vec::new(),
+            pending_epoch_limit: 0,
+            pending_from_epoch: None,
+        }
+    }
+
+    #[test]
+    fn queued_policy_waits_for_the_next_epoch() {
+        let dest = Pubkey::new_unique();
+        let mut t = treasury();
+
+        assert_eq!(t.queue_policy(vec![dest], 500, 5).unwrap(), 6);
+        assert!(t.record_withdrawal(&dest, 1, 5).is_err());
+        assert!(t.allowed_destinations.is_empty());
+
+        t.record_withdrawal(&dest, 500, 6).unwrap();
+        assert_eq!(t.allowed_destinations, vec![dest]);
+        assert_eq!(t.epoch_limit, 500);
+        assert_eq!(t.pending_from_epoch, None);
+        assert!(t.record_withdrawal(&dest, 1, 6).is_err());
+        t.record_withdrawal(&dest, 1, 7).unwrap();
+        assert_eq!(t.total_withdrawn, 501);
+    }
+
+    #[test]
+    fn queue_policy_rejects_a_zero_limit() {
+        let mut t = treasury();
+        assert!(t.queue_policy(vec![Pubkey::new_unique()], 0, 5).is_err());
+        assert_eq!(t.pending_from_epoch, None);
+    }
+}
@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use crate::errors::CustomError;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum BetStatus {
     Active,
     Calculated,
     Settled,
+    /// Cashed out before `end_time`; takes no part in settlement
+    Exited,
 }
 
 #[account]
@@ -35,8 +38,33 @@ pub struct UserBet {
     pub status: BetStatus,
 
     pub bump: u8,
+
+    // --- Appended after `bump`, in the SPACE padding ---
+    pub referrer: Option<Pubkey>,
+    /// Share of this bet's entry fee credited to the referrer
+    pub referral_fee: u64,
+    /// Series streak carried into the pool's round, snapshot at place_bet
+    pub series_streak: u32,
+    pub bonus_claimed: bool,
+    /// Counted in the pool's `revealed_count`
+    pub reveal_counted: bool,
 }
 
 impl UserBet {
     pub const SPACE: usize = 400;
+
+    /// Loads a bet for settlement. While delegated, the base-layer copy is
+    /// owned by the delegation program and may be stale, so it is rejected.
+    pub fn load_undelegated(info: &AccountInfo) -> Result<UserBet> {
+        require_keys_eq!(*info.owner, crate::ID, CustomError::BetStillDelegated);
+        let data = info.try_borrow_data()?;
+        UserBet::try_deserialize(&mut &data[..])
+    }
+
+    /// Writes back a bet loaded with `load_undelegated`.
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        self.try_serialize(&mut writer)
+    }
 }
\ No newline at end of file
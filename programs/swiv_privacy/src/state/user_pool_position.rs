@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_POSITION_BETS, MAX_REQUEST_ID_LEN};
+use crate::errors::CustomError;
+use crate::state::UserProfile;
+use crate::utils::SafeMath;
+
+/// One per (pool, user): indexes the user's bets in the pool so wallets can
+/// derive every UserBet PDA without a `getProgramAccounts` scan.
+#[account]
+pub struct UserPoolPosition {
+    pub owner: Pubkey,
+    pub pool: Pubkey,
+    /// Bets ever placed in the pool
+    pub bet_count: u32,
+    /// Bets not yet claimed or cashed out
+    pub active_bets: u32,
+    pub request_ids: Vec<String>,
+    pub bump: u8,
+}
+
+impl UserPoolPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + 4 + 4 + MAX_POSITION_BETS * (4 + MAX_REQUEST_ID_LEN) + 1;
+
+    /// Records a new bet; the pool becomes active on the profile with its first open bet.
+    /// A position indexes at most MAX_POSITION_BETS bets (PositionFull), whatever
+    /// the pool's own `max_bets_per_user`.
+    pub fn open_bet(&mut self, request_id: String, profile: &mut UserProfile) -> Result<()> {
+        require!(request_id.len() <= MAX_REQUEST_ID_LEN, CustomError::RequestIdTooLong);
+        require!(self.request_ids.len() < MAX_POSITION_BETS, CustomError::PositionFull);
+
+        if self.active_bets == 0 {
+            profile.add_active_pool(self.pool)?;
+        }
+
+        self.request_ids.push(request_id);
+        self.bet_count = self.bet_count.safe_add(1)?;
+        self.active_bets = self.active_bets.safe_add(1)?;
+        profile.total_bets = profile.total_bets.safe_add(1)?;
+        Ok(())
+    }
+
+    /// Called when a bet is claimed or cashed out; the pool leaves the
+    /// profile's active list with the last open bet.
+    pub fn close_bet(&mut self, profile: &mut UserProfile) -> Result<()> {
+        self.active_bets = self.active_bets.saturating_sub(1);
+        if self.active_bets == 0 {
+            profile.remove_active_pool(&self.pool);
+        }
+        Ok(())
+    }
+
+    /// Drops the pool from the profile's active list even though bets remain
+    /// open (never revealed or never calculated). Claims still work afterwards.
+    pub fn release(&mut self, profile: &mut UserProfile) {
+        self.active_bets = 0;
+        profile.remove_active_pool(&self.pool);
+    }
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_ACTIVE_POOLS, MAX_TRACKED_SERIES};
+use crate::errors::CustomError;
+use crate::utils::SafeMath;
+
+/// A user's run of consecutive scoring rounds in a pool series.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct SeriesStreak {
+    pub series_id: u64,
+    pub last_round: u64,
+    /// Consecutive scoring rounds up to and including `last_round`
+    pub streak: u32,
+    /// Streak going into `last_round`, for further bets in that round
+    pub streak_before_last: u32,
+}
+
+impl SeriesStreak {
+    pub const LEN: usize = 8 + 8 + 4 + 4;
+
+    /// Streak carried into `round`.
+    fn streak_into(&self, round: u64) -> u32 {
+        if self.last_round == round {
+            self.streak_before_last
+        } else if round > 0 && self.last_round == round - 1 {
+            self.streak
+        } else {
+            0
+        }
+    }
+}
+
+/// One per user: the pools the user currently has open bets in.
+#[account]
+pub struct UserProfile {
+    pub owner: Pubkey,
+    pub active_pools: Vec<Pubkey>,
+    pub total_bets: u64,
+    pub created_ts: i64,
+
+    /// Streaks in the series the user plays most recently
+    pub series_streaks: Vec<SeriesStreak>,
+    pub bump: u8,
+}
+
+impl UserProfile {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_ACTIVE_POOLS + 8 + 8
+        + 4 + SeriesStreak::LEN * MAX_TRACKED_SERIES
+        + 1;
+
+    pub fn add_active_pool(&mut self, pool: Pubkey) -> Result<()> {
+        if !self.active_pools.contains(&pool) {
+            require!(self.active_pools.len() < MAX_ACTIVE_POOLS, CustomError::TooManyActivePools);
+            self.active_pools.push(pool);
+        }
+        Ok(())
+    }
+
+    /// Consecutive scoring rounds before `round` of the series.
+    pub fn streak_into(&self, series_id: u64, round: u64) -> u32 {
+        self.series_streaks
+            .iter()
+            .find(|entry| entry.series_id == series_id)
+            .map_or(0, |entry| entry.streak_into(round))
+    }
+
+    /// Records a calculated bet in `round` of the series: any scoring bet
+    /// extends the streak, a round without one resets it. Results for a round
+    /// older than the last recorded one are ignored.
+    pub fn record_series_result(&mut self, series_id: u64, round: u64, scored: bool) -> Result<()> {
+        let index = match self.series_streaks.iter().position(|entry| entry.series_id == series_id) {
+            Some(index) => index,
+            None => {
+                let entry = SeriesStreak { series_id, last_round: 0, streak: 0, streak_before_last: 0 };
+                if self.series_streaks.len() < MAX_TRACKED_SERIES {
+                    self.series_streaks.push(entry);
+                    self.series_streaks.len() - 1
+                } else {
+                    // Full (MAX_TRACKED_SERIES entries, so never empty): replace the weakest streak
+                    let (index, _) = self.series_streaks
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, entry)| entry.streak)
+                        .ok_or(CustomError::MathOverflow)?;
+                    self.series_streaks[index] = entry;
+                    index
+                }
+            }
+        };
+
+        let entry = &mut self.series_streaks[index];
+        if round < entry.last_round {
+            return Ok(());
+        }
+        let before = entry.streak_into(round);
+        if entry.last_round != round || scored {
+            entry.streak = if scored { before.safe_add(1)? } else { 0 };
+        }
+        entry.streak_before_last = before;
+        entry.last_round = round;
+        Ok(())
+    }
+
+    pub fn remove_active_pool(&mut self, pool: &Pubkey) {
+        self.active_pools.retain(|p| p != pool);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            owner: Pubkey::default(),
+            active_pools: Vec::new(),
+            total_bets: 0,
+            created_ts: 0,
+            series_streaks: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn streak_into_carries_only_into_the_next_round() {
+        let entry = SeriesStreak { series_id: 1, last_round: 5, streak: 3, streak_before_last: 2 };
+        assert_eq!(entry.streak_into(6), 3);
+        // Further bets in the last round keep the streak they started with
+        assert_eq!(entry.streak_into(5), 2);
+        // A skipped round breaks the streak
+        assert_eq!(entry.streak_into(7), 0);
+        assert_eq!(entry.streak_into(4), 0);
+        assert_eq!(SeriesStreak { series_id: 1, last_round: 0, streak: 1, streak_before_last: 0 }.streak_into(0), 0);
+    }
+
+    #[test]
+    fn record_series_result_builds_and_resets_streaks() {
+        let mut profile = profile();
+        for round in 1..=3 {
+            assert_eq!(profile.streak_into(7, round), round as u32 - 1);
+            profile.record_series_result(7, round, true).unwrap();
+        }
+        assert_eq!(profile.streak_into(7, 4), 3);
+
+        profile.record_series_result(7, 4, false).unwrap();
+        assert_eq!(profile.streak_into(7, 5), 0);
+        // Other series are tracked separately
+        assert_eq!(profile.streak_into(8, 1), 0);
+    }
+
+    #[test]
+    fn record_series_result_counts_a_round_once() {
+        let mut profile = profile();
+        profile.record_series_result(1, 1, true).unwrap();
+        profile.record_series_result(1, 2, false).unwrap();
+        // A second, scoring bet in round 2 still extends the streak from round 1
+        profile.record_series_result(1, 2, true).unwrap();
+        assert_eq!(profile.streak_into(1, 3), 2);
+        // A later miss in the same round does not undo it
+        profile.record_series_result(1, 2, false).unwrap();
+        assert_eq!(profile.streak_into(1, 3), 2);
+        assert_eq!(profile.streak_into(1, 2), 1);
+    }
+
+    #[test]
+    fn record_series_result_ignores_older_rounds() {
+        let mut profile = profile();
+        profile.record_series_result(1, 4, true).unwrap();
+        profile.record_series_result(1, 5, true).unwrap();
+        profile.record_series_result(1, 3, false).unwrap();
+
+        let entry = profile.series_streaks[0];
+        assert_eq!(entry.last_round, 5);
+        assert_eq!(profile.streak_into(1, 6), 2);
+    }
+
+    #[test]
+    fn record_series_result_replaces_the_weakest_series_when_full() {
+        let mut profile = profile();
+        for series_id in 1..=MAX_TRACKED_SERIES as u64 {
+            profile.record_series_result(series_id, 1, series_id != 3).unwrap();
+        }
+        profile.record_series_result(100, 1, true).unwrap();
+
+        assert_eq!(profile.series_streaks.len(), MAX_TRACKED_SERIES);
+        assert!(profile.series_streaks.iter().all(|entry| entry.series_id != 3));
+        assert_eq!(profile.streak_into(100, 2), 1);
+    }
+}
@@ -1,4 +1,7 @@
+use crate::constants::STREAK_BONUS_STEP_BPS;
 use crate::errors::CustomError;
+use crate::state::AccuracyCurve;
+use crate::utils::{mul_div, SafeMath};
 use anchor_lang::prelude::*;
 
 pub const MATH_PRECISION: u128 = 1_000_000; 
@@ -15,6 +18,7 @@ pub fn calculate_accuracy_score(
         return Ok(0); 
     }
 
+    #[allow(clippy::manual_abs_diff)]
     let diff = if prediction > result {
         prediction - result
     } else {
@@ -29,45 +33,110 @@ pub fn calculate_accuracy_score(
     let buffer_u128 = buffer as u128;
 
     let error_fraction = diff_u128
-        .checked_mul(MATH_PRECISION)
-        .ok_or(CustomError::MathOverflow)?
-        .checked_div(buffer_u128)
-        .ok_or(CustomError::MathOverflow)?;
+        .safe_mul(MATH_PRECISION)?
+        .safe_div(buffer_u128)?;
 
     let score = MATH_PRECISION.saturating_sub(error_fraction);
 
     Ok(score as u64)
 }
 
+// --- 1b. ACCURACY CURVES ---
+/// exp(-i / 16) for i = 0..=64, scaled by MATH_PRECISION
+const EXP_NEG_TABLE: [u64; 65] = [
+    1_000_000, 939_413, 882_497, 829_029, 778_801, 731_616, 687_289, 645_649,
+    606_531, 569_783, 535_261, 502_832, 472_367, 443_747, 416_862, 391_606,
+    367_879, 345_591, 324_652, 304_983, 286_505, 269_146, 252_840, 237_521,
+    223_130, 209_611, 196_912, 184_981, 173_774, 163_246, 153_355, 144_064,
+    135_335, 127_136, 119_433, 112_197, 105_399, 99_013, 93_014, 87_379,
+    82_085, 77_112, 72_440, 68_051, 63_928, 60_055, 56_416, 52_998,
+    49_787, 46_771, 43_937, 41_275, 38_774, 36_425, 34_218, 32_145,
+    30_197, 28_368, 26_649, 25_035, 23_518, 22_093, 20_754, 19_497,
+    18_316,
+];
+
+/// exp(-x) for a MATH_PRECISION-scaled x in [0, 4], linearly interpolated
+/// between table entries. Larger x is clamped to exp(-4).
+pub fn exp_neg(x_scaled: u128) -> u64 {
+    let steps = x_scaled.saturating_mul(16);
+    let index = (steps / MATH_PRECISION) as usize;
+    if index >= EXP_NEG_TABLE.len() - 1 {
+        return EXP_NEG_TABLE[EXP_NEG_TABLE.len() - 1];
+    }
+
+    let fraction = steps % MATH_PRECISION;
+    let upper = EXP_NEG_TABLE[index] as u128;
+    let lower = EXP_NEG_TABLE[index + 1] as u128;
+    (upper - (upper - lower) * fraction / MATH_PRECISION) as u64
+}
+
+/// Accuracy for the pool's curve, between 0 and MATH_PRECISION.
+/// With e = |Prediction - Result| / Buffer (0 outside the buffer):
+/// - Linear: 1 - e
+/// - Quadratic: 1 - e^2
+/// - Exponential: exp(-4 e^2), ~0.018 at the edge of the buffer
+/// - Relative: 1 - e, where the miss is measured in bps of Result and
+///   Buffer is given in bps (a $10 miss on BTC is not a $10 miss on SOL)
+pub fn calculate_curve_accuracy_score(
+    curve: AccuracyCurve,
+    prediction: u64,
+    result: u64,
+    buffer: u64,
+) -> Result<u64> {
+    let (diff, buffer) = match curve {
+        AccuracyCurve::Linear => return calculate_accuracy_score(prediction, result, buffer),
+        AccuracyCurve::Relative => {
+            if result == 0 {
+                return Ok(if prediction == 0 { MATH_PRECISION as u64 } else { 0 });
+            }
+            let diff_bps = mul_div(prediction.abs_diff(result) as u128, 10_000, result as u128)?;
+            (diff_bps, buffer as u128)
+        }
+        _ => (prediction.abs_diff(result) as u128, buffer as u128),
+    };
+
+    if buffer == 0 || diff >= buffer {
+        return Ok(0);
+    }
+
+    // Normalized error e in [0, 1)
+    let error = diff.safe_mul(MATH_PRECISION)?.safe_div(buffer)?;
+    let error_squared = error.safe_mul(error)?.safe_div(MATH_PRECISION)?;
+
+    let score = match curve {
+        AccuracyCurve::Linear | AccuracyCurve::Relative => MATH_PRECISION.saturating_sub(error),
+        AccuracyCurve::Quadratic => MATH_PRECISION.saturating_sub(error_squared),
+        AccuracyCurve::Exponential => exp_neg(error_squared.safe_mul(4)?) as u128,
+    };
+
+    Ok(score as u64)
+}
+
 // --- 2. TIME BONUS (Linear Decay) ---
 /// Formula: Factor = 1.0 + ( (EndTime - EntryTime) / TotalDuration )
 /// - Entry at Start: Bonus = 1.0 + 1.0 = 2.0x
 /// - Entry at End: Bonus = 1.0 + 0.0 = 1.0x
+/// - Entry before Start is clamped to Start (max 2.0x)
 pub fn calculate_time_bonus(
     start_time: i64,
     end_time: i64,
     entry_time: i64,
 ) -> Result<u64> {
-    if entry_time >= end_time {
+    if entry_time >= end_time || end_time <= start_time {
         return Ok(MATH_PRECISION as u64); // 1.0x (No bonus)
     }
 
-    let total_duration = (end_time - start_time) as u128;
-    let remaining_time = (end_time - entry_time) as u128;
-
-    if total_duration == 0 {
-        return Ok(MATH_PRECISION as u64);
-    }
+    let entry_time = entry_time.max(start_time);
+    let total_duration = end_time.safe_sub(start_time)? as u128;
+    let remaining_time = end_time.safe_sub(entry_time)? as u128;
 
     // Bonus Portion = Remaining / Total
     let bonus_portion = remaining_time
-        .checked_mul(MATH_PRECISION)
-        .unwrap()
-        .checked_div(total_duration)
-        .unwrap();
+        .safe_mul(MATH_PRECISION)?
+        .safe_div(total_duration)?;
 
     // Total Factor = 1.0 + Bonus Portion
-    let factor = MATH_PRECISION.checked_add(bonus_portion).unwrap();
+    let factor = MATH_PRECISION.safe_add(bonus_portion)?;
 
     Ok(factor as u64)
 }
@@ -84,30 +153,268 @@ pub fn calculate_conviction_bonus(update_count: u32) -> u64 {
     }
 }
 
+// --- 3b. STREAK BONUS ---
+/// Factor = 1.0 + min(Streak * STREAK_BONUS_STEP_BPS, Cap), scaled by MATH_PRECISION
+pub fn calculate_streak_bonus(streak: u32, max_bonus_bps: u64) -> Result<u64> {
+    let bonus_bps = (streak as u64)
+        .safe_mul(STREAK_BONUS_STEP_BPS)?
+        .min(max_bonus_bps);
+
+    (MATH_PRECISION as u64).safe_add(bonus_bps.safe_mul(100)?)
+}
+
 // --- 4. MASTER WEIGHT CALCULATION ---
-/// Weight = Stake * Accuracy * Time * Conviction
+/// Weight = Stake * Accuracy * Time * Conviction, kept at MATH_PRECISION
+/// (1 stake unit = 1,000,000 weight) so small stakes do not round to zero.
+/// Weights are only ever compared with each other, so the scale cancels out.
 pub fn calculate_parimutuel_weight(
     stake: u64,
     accuracy_score_scaled: u64, // 0 to 1,000,000
     time_bonus_scaled: u64,     // 1,000,000 to 2,000,000
     conviction_scaled: u64,     // 1,000,000 or 1,500,000
 ) -> Result<u128> {
-    
-    // We do all multiplication in u128
-    let stake_u128 = stake as u128;
-    
-    // Perform multiplication
-    let raw_product = stake_u128
-        .checked_mul(accuracy_score_scaled as u128).unwrap()
-        .checked_mul(time_bonus_scaled as u128).unwrap()
-        .checked_mul(conviction_scaled as u128).unwrap();
-
-    // Divide by Precision^3 because we multiplied 3 scaled numbers
-    // Result is the "Weight" in raw units relative to stake
-    let final_weight = raw_product
-        .checked_div(MATH_PRECISION).unwrap()
-        .checked_div(MATH_PRECISION).unwrap()
-        .checked_div(MATH_PRECISION).unwrap();
-
-    Ok(final_weight)
-}   
\ No newline at end of file
+    // Combined factor at Precision^3 (at most ~3e18, fits easily in u128)
+    let factor = (accuracy_score_scaled as u128)
+        .safe_mul(time_bonus_scaled as u128)?
+        .safe_mul(conviction_scaled as u128)?;
+
+    // Single rounding step: Stake * Factor / Precision^2 (256-bit intermediate)
+    mul_div(stake as u128, factor, MATH_PRECISION.safe_mul(MATH_PRECISION)?)
+}
+
+/// Share of `pot` owed to `weight` out of `total_weight`, rounding down.
+pub fn calculate_weighted_share(weight: u128, total_weight: u128, pot: u64) -> Result<u64> {
+    let share = mul_div(weight, pot as u128, total_weight)?;
+    u64::try_from(share).map_err(|_| error!(CustomError::MathOverflow))
+}
+
+// --- 4b. SEASON POINTS ---
+/// Accuracy only (0 to 1,000 per bet), so points compare across stake sizes.
+pub fn calculate_season_points(accuracy_score_scaled: u64) -> u64 {
+    accuracy_score_scaled / 1_000
+}
+
+// --- 5. CASH-OUT PENALTY (Linear Ramp) ---
+/// Returns the penalty in basis points.
+/// Formula: Penalty = Min + (Max - Min) * ( (ExitTime - StartTime) / TotalDuration )
+/// - Exit at Start: Min penalty
+/// - Exit at End: Max penalty (steeper closer to close)
+pub fn calculate_cash_out_penalty_bps(
+    start_time: i64,
+    end_time: i64,
+    exit_time: i64,
+    min_penalty_bps: u64,
+    max_penalty_bps: u64,
+) -> Result<u64> {
+    if exit_time <= start_time || end_time <= start_time {
+        return Ok(min_penalty_bps);
+    }
+    if exit_time >= end_time {
+        return Ok(max_penalty_bps);
+    }
+
+    let total_duration = end_time.safe_sub(start_time)? as u128;
+    let elapsed = exit_time.safe_sub(start_time)? as u128;
+    let range = max_penalty_bps.saturating_sub(min_penalty_bps) as u128;
+
+    let ramp = range
+        .safe_mul(elapsed)?
+        .safe_div(total_duration)?;
+
+    min_penalty_bps.safe_add(ramp as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::safe_math::tests::XorShift;
+
+    const SAMPLES: usize = 2_000;
+
+    #[test]
+    fn accuracy_score_decreases_with_distance() {
+        let mut rng = XorShift(0x51f1_5eed_0000_0042);
+        for _ in 0..SAMPLES {
+            let result = rng.next_u64() >> 2;
+            let buffer = rng.next_u64() % 1_000_000_000 + 1;
+            let near = rng.next_u64() % (buffer * 2);
+            let far = near + rng.next_u64() % buffer;
+
+            let near_score = calculate_accuracy_score(result + near, result, buffer).unwrap();
+            let far_score = calculate_accuracy_score(result + far, result, buffer).unwrap();
+            assert!(far_score <= near_score);
+            assert!(near_score <= MATH_PRECISION as u64);
+            // Symmetric around the result
+            let below = calculate_accuracy_score(result - near.min(result), result, buffer).unwrap();
+            let above = calculate_accuracy_score(result + near.min(result), result, buffer).unwrap();
+            assert_eq!(below, above);
+        }
+        assert_eq!(calculate_accuracy_score(100, 100, 10).unwrap(), MATH_PRECISION as u64);
+        assert_eq!(calculate_accuracy_score(110, 100, 10).unwrap(), 0);
+        assert_eq!(calculate_accuracy_score(100, 100, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn time_bonus_decreases_with_later_entry() {
+        let mut rng = XorShift(0x7133_b0b0_5eed_0001);
+        for _ in 0..SAMPLES {
+            let start = (rng.next_u64() >> 34) as i64;
+            let end = start + (rng.next_u64() % 1_000_000) as i64 + 1;
+            let early = start - 10 + (rng.next_u64() % (end - start + 20) as u64) as i64;
+            let late = early + (rng.next_u64() % 1_000) as i64;
+
+            let early_bonus = calculate_time_bonus(start, end, early).unwrap();
+            let late_bonus = calculate_time_bonus(start, end, late).unwrap();
+            assert!(late_bonus <= early_bonus);
+            assert!((MATH_PRECISION as u64..=2 * MATH_PRECISION as u64).contains(&early_bonus));
+        }
+        assert_eq!(calculate_time_bonus(100, 200, 0).unwrap(), 2 * MATH_PRECISION as u64);
+        assert_eq!(calculate_time_bonus(100, 200, 200).unwrap(), MATH_PRECISION as u64);
+    }
+
+    #[test]
+    fn weight_is_monotonic_in_every_factor() {
+        let mut rng = XorShift(0x00c0_ffee_1234_5678);
+        for _ in 0..SAMPLES {
+            let stake = rng.next_u64();
+            let accuracy = rng.next_u64() % (MATH_PRECISION as u64 + 1);
+            let time = MATH_PRECISION as u64 + rng.next_u64() % (MATH_PRECISION as u64 + 1);
+            let conviction = calculate_conviction_bonus((rng.next_u64() % 2) as u32);
+            let weight = calculate_parimutuel_weight(stake, accuracy, time, conviction).unwrap();
+
+            let more_stake = stake.saturating_add(rng.next_u64() >> 8);
+            assert!(calculate_parimutuel_weight(more_stake, accuracy, time, conviction).unwrap() >= weight);
+            let more_accuracy = (accuracy + 1).min(MATH_PRECISION as u64);
+            assert!(calculate_parimutuel_weight(stake, more_accuracy, time, conviction).unwrap() >= weight);
+            assert!(calculate_parimutuel_weight(stake, accuracy, time + 1, conviction).unwrap() >= weight);
+            // Never more than Stake * 2.0 * 1.5 at MATH_PRECISION
+            assert!(weight <= stake as u128 * 3 * MATH_PRECISION);
+        }
+    }
+
+    #[test]
+    fn weighted_shares_never_exceed_the_pot() {
+        let mut rng = XorShift(0x5ca1_ab1e_0000_0007);
+        for _ in 0..SAMPLES / 10 {
+            let pot = rng.next_u64();
+            let weights: Vec<u128> = (0..1 + rng.next_u64() % 20).map(|_| rng.next_sized_u128() >> 8).collect();
+            let total: u128 = weights.iter().sum();
+            if total == 0 {
+                continue;
+            }
+
+            let mut paid: u128 = 0;
+            for weight in &weights {
+                let share = calculate_weighted_share(*weight, total, pot).unwrap();
+                let more = calculate_weighted_share((*weight + 1).min(total), total, pot).unwrap();
+                assert!(share <= more);
+                paid += share as u128;
+            }
+            assert!(paid <= pot as u128);
+            // Rounding down loses less than one unit per bet
+            assert!(pot as u128 - paid < weights.len() as u128);
+        }
+    }
+
+    #[test]
+    fn streak_bonus_grows_until_the_cap() {
+        let cap = 2_000;
+        let mut previous = calculate_streak_bonus(0, cap).unwrap();
+        assert_eq!(previous, MATH_PRECISION as u64);
+        for streak in 1..20 {
+            let bonus = calculate_streak_bonus(streak, cap).unwrap();
+            assert!(bonus >= previous);
+            assert!(bonus <= MATH_PRECISION as u64 + cap * 100);
+            previous = bonus;
+        }
+        assert_eq!(calculate_streak_bonus(u32::MAX, cap).unwrap(), MATH_PRECISION as u64 + cap * 100);
+    }
+
+    #[test]
+    fn exp_neg_hits_table_entries_and_boundaries() {
+        for (i, expected) in EXP_NEG_TABLE.iter().enumerate() {
+            assert_eq!(exp_neg(i as u128 * MATH_PRECISION / 16), *expected);
+        }
+        // Past the last entry clamps to exp(-4)
+        assert_eq!(exp_neg(4 * MATH_PRECISION), 18_316);
+        assert_eq!(exp_neg(4 * MATH_PRECISION + 1), 18_316);
+        assert_eq!(exp_neg(u128::MAX), 18_316);
+        // Halfway between two entries interpolates between them
+        let mid = exp_neg(MATH_PRECISION / 32);
+        assert!(EXP_NEG_TABLE[1] < mid && mid < EXP_NEG_TABLE[0]);
+    }
+
+    #[test]
+    fn exp_neg_is_non_increasing() {
+        let mut previous = exp_neg(0);
+        for x in (0..=4 * MATH_PRECISION + 1_000).step_by(997) {
+            let value = exp_neg(x);
+            assert!(value <= previous);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn curves_score_an_exact_hit_fully_and_the_buffer_edge_at_zero() {
+        for curve in [AccuracyCurve::Linear, AccuracyCurve::Quadratic, AccuracyCurve::Relative] {
+            assert_eq!(calculate_curve_accuracy_score(curve, 1_000, 1_000, 100).unwrap(), MATH_PRECISION as u64);
+            assert_eq!(calculate_curve_accuracy_score(curve, 2_000, 1_000, 100).unwrap(), 0);
+        }
+        assert_eq!(
+            calculate_curve_accuracy_score(AccuracyCurve::Exponential, 1_000, 1_000, 100).unwrap(),
+            MATH_PRECISION as u64
+        );
+        // Exponential ends at exp(-4) inside the buffer, 0 outside
+        assert!(calculate_curve_accuracy_score(AccuracyCurve::Exponential, 1_099, 1_000, 100).unwrap() >= 18_316);
+        assert_eq!(calculate_curve_accuracy_score(AccuracyCurve::Exponential, 1_100, 1_000, 100).unwrap(), 0);
+    }
+
+    #[test]
+    fn curves_at_half_the_buffer() {
+        let score = |curve| calculate_curve_accuracy_score(curve, 1_050, 1_000, 100).unwrap();
+        assert_eq!(score(AccuracyCurve::Linear), 500_000);
+        assert_eq!(score(AccuracyCurve::Quadratic), 750_000);
+        // exp(-1)
+        assert_eq!(score(AccuracyCurve::Exponential), 367_879);
+        // 50 on 1,000 is 500 bps: half of a 1,000 bps buffer
+        assert_eq!(calculate_curve_accuracy_score(AccuracyCurve::Relative, 1_050, 1_000, 1_000).unwrap(), 500_000);
+    }
+
+    #[test]
+    fn curves_decrease_with_distance() {
+        let mut rng = XorShift(0xc0ff_ee00_0000_0043);
+        let curves = [AccuracyCurve::Linear, AccuracyCurve::Quadratic, AccuracyCurve::Exponential, AccuracyCurve::Relative];
+        for _ in 0..SAMPLES {
+            let result = rng.next_u64() >> 4;
+            let buffer = rng.next_u64() % 100_000 + 1;
+            let near = rng.next_u64() % (buffer * 2);
+            let far = near + rng.next_u64() % buffer;
+            for curve in curves {
+                let near_score = calculate_curve_accuracy_score(curve, result + near, result, buffer).unwrap();
+                let far_score = calculate_curve_accuracy_score(curve, result + far, result, buffer).unwrap();
+                assert!(far_score <= near_score);
+                assert!(near_score <= MATH_PRECISION as u64);
+            }
+        }
+        // Relative on a zero outcome: only an exact hit scores
+        assert_eq!(calculate_curve_accuracy_score(AccuracyCurve::Relative, 0, 0, 100).unwrap(), MATH_PRECISION as u64);
+        assert_eq!(calculate_curve_accuracy_score(AccuracyCurve::Relative, 1, 0, 100).unwrap(), 0);
+    }
+
+    #[test]
+    fn cash_out_penalty_ramps_between_bounds() {
+        let mut rng = XorShift(0x0bad_cafe_0000_0003);
+        for _ in 0..SAMPLES {
+            let start = (rng.next_u64() >> 34) as i64;
+            let end = start + (rng.next_u64() % 1_000_000) as i64 + 1;
+            let (min, max) = (rng.next_u64() % 5_000, 5_000 + rng.next_u64() % 5_001);
+            let early = start - 10 + (rng.next_u64() % (end - start + 20) as u64) as i64;
+            let late = early + (rng.next_u64() % 1_000) as i64;
+
+            let early_penalty = calculate_cash_out_penalty_bps(start, end, early, min, max).unwrap();
+            let late_penalty = calculate_cash_out_penalty_bps(start, end, late, min, max).unwrap();
+            assert!(early_penalty <= late_penalty);
+            assert!((min..=max).contains(&early_penalty));
+        }
+    }
+}
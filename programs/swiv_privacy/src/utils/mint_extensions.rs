@@ -0,0 +1,36 @@
+use crate::errors::CustomError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+};
+
+/// Token-2022 extensions that break vault accounting or let a third party
+/// move / freeze pool funds.
+pub const UNSUPPORTED_MINT_EXTENSIONS: [ExtensionType; 5] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+    ExtensionType::NonTransferable,
+    ExtensionType::DefaultAccountState,
+    ExtensionType::Pausable,
+];
+
+/// Accepts legacy SPL mints and Token-2022 mints without unsupported extensions.
+/// Transfer fees are allowed: callers credit the amount actually received.
+pub fn validate_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    for extension in state.get_extension_types()? {
+        require!(
+            !UNSUPPORTED_MINT_EXTENSIONS.contains(&extension),
+            CustomError::UnsupportedMintExtension
+        );
+    }
+
+    Ok(())
+}
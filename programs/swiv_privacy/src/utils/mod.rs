@@ -1,3 +1,9 @@
 pub mod fixed_math;
+pub mod mint_extensions;
+pub mod safe_math;
+pub mod vault;
 
-pub use fixed_math::*;
\ No newline at end of file
+pub use fixed_math::*;
+pub use mint_extensions::*;
+pub use safe_math::*;
+pub use vault::*;
\ No newline at end of file
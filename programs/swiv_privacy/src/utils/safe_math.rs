@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+
+// ------------------------------------------------------------------
+// CHECKED MATH
+// ------------------------------------------------------------------
+// Checked arithmetic that fails with `CustomError::MathOverflow` instead of
+// panicking on `.unwrap()`.
+
+pub trait SafeMath: Sized {
+    fn safe_add(self, rhs: Self) -> Result<Self>;
+    fn safe_sub(self, rhs: Self) -> Result<Self>;
+    fn safe_mul(self, rhs: Self) -> Result<Self>;
+    fn safe_div(self, rhs: Self) -> Result<Self>;
+}
+
+macro_rules! impl_safe_math {
+    ($($t:ty),*) => {
+        $(
+            impl SafeMath for $t {
+                fn safe_add(self, rhs: Self) -> Result<Self> {
+                    self.checked_add(rhs).ok_or_else(|| error!(CustomError::MathOverflow))
+                }
+
+                fn safe_sub(self, rhs: Self) -> Result<Self> {
+                    self.checked_sub(rhs).ok_or_else(|| error!(CustomError::MathOverflow))
+                }
+
+                fn safe_mul(self, rhs: Self) -> Result<Self> {
+                    self.checked_mul(rhs).ok_or_else(|| error!(CustomError::MathOverflow))
+                }
+
+                fn safe_div(self, rhs: Self) -> Result<Self> {
+                    self.checked_div(rhs).ok_or_else(|| error!(CustomError::MathOverflow))
+                }
+            }
+        )*
+    };
+}
+
+impl_safe_math!(u32, u64, u128, i64, i128);
+
+/// `amount * bps / 10_000`, computed in u128 so large amounts cannot overflow.
+pub fn apply_bps(amount: u64, bps: u64) -> Result<u64> {
+    let value = (amount as u128).safe_mul(bps as u128)?.safe_div(10_000)?;
+    u64::try_from(value).map_err(|_| error!(CustomError::MathOverflow))
+}
+
+/// `a * b / denominator` with a 256-bit intermediate product, rounding down.
+/// Fails only if the denominator is zero or the quotient exceeds u128.
+pub fn mul_div(a: u128, b: u128, denominator: u128) -> Result<u128> {
+    require!(denominator != 0, CustomError::MathOverflow);
+
+    if let Some(product) = a.checked_mul(b) {
+        return Ok(product / denominator);
+    }
+
+    let (hi, lo) = widening_mul(a, b);
+    require!(hi < denominator, CustomError::MathOverflow);
+
+    // Restoring long division of (hi, lo) by the denominator, one bit at a time
+    let mut remainder = hi;
+    let mut quotient: u128 = 0;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+
+    Ok(quotient)
+}
+
+/// Full 256-bit product of two u128 values, as (high, low) halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_lo, a_hi) = (a & MASK, a >> 64);
+    let (b_lo, b_hi) = (b & MASK, b >> 64);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Deterministic xorshift generator for the property tests.
+    pub(crate) struct XorShift(pub u64);
+
+    impl XorShift {
+        pub(crate) fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        pub(crate) fn next_u128(&mut self) -> u128 {
+            ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+        }
+
+        /// Uniform over bit lengths, so small and huge values are both common.
+        pub(crate) fn next_sized_u128(&mut self) -> u128 {
+            let bits = self.next_u64() % 129;
+            if bits == 0 { 0 } else { self.next_u128() >> (128 - bits) }
+        }
+    }
+
+    const SAMPLES: usize = 5_000;
+
+    /// (hi, lo) - (hi2, lo2) for a 256-bit minuend that is not smaller.
+    fn sub_256(a: (u128, u128), b: (u128, u128)) -> (u128, u128) {
+        let (lo, borrow) = a.1.overflowing_sub(b.1);
+        (a.0 - b.0 - borrow as u128, lo)
+    }
+
+    #[test]
+    fn widening_mul_matches_native_product() {
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..SAMPLES {
+            let (a, b) = (rng.next_u64() as u128, rng.next_sized_u128());
+            if let Some(product) = a.checked_mul(b) {
+                assert_eq!(widening_mul(a, b), (0, product));
+            }
+            assert_eq!(widening_mul(a, b), widening_mul(b, a));
+        }
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul(1 << 127, 2), (1, 0));
+    }
+
+    #[test]
+    fn widening_mul_distributes_over_addition() {
+        // a * (b + 1) == a * b + a
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        for _ in 0..SAMPLES {
+            let (a, b) = (rng.next_sized_u128(), rng.next_sized_u128() >> 1);
+            let (hi, lo) = widening_mul(a, b);
+            let (lo_sum, carry) = lo.overflowing_add(a);
+            assert_eq!(widening_mul(a, b + 1), (hi + carry as u128, lo_sum));
+        }
+    }
+
+    #[test]
+    fn mul_div_is_floor_of_exact_quotient() {
+        // q * d <= a * b < (q + 1) * d
+        let mut rng = XorShift(0xdead_beef_cafe_f00d);
+        for _ in 0..SAMPLES {
+            let (a, b, d) = (rng.next_sized_u128(), rng.next_sized_u128(), rng.next_sized_u128().max(1));
+            let product = widening_mul(a, b);
+            let Ok(q) = mul_div(a, b, d) else {
+                // Only a quotient above u128 may fail
+                assert!(product.0 >= d);
+                continue;
+            };
+            let remainder = sub_256(product, widening_mul(q, d));
+            assert_eq!(remainder.0, 0);
+            assert!(remainder.1 < d);
+        }
+    }
+
+    #[test]
+    fn mul_div_bounds() {
+        let mut rng = XorShift(0x0123_4567_89ab_cdef);
+        for _ in 0..SAMPLES {
+            let (a, b) = (rng.next_sized_u128(), rng.next_sized_u128().max(1));
+            // b / b == 1 even when a * b overflows u128
+            assert_eq!(mul_div(a, b, b).unwrap(), a);
+            // A fraction <= 1 never grows the value
+            let d = b.saturating_add(rng.next_sized_u128());
+            assert!(mul_div(a, b, d).unwrap() <= a);
+        }
+        assert!(mul_div(1, 1, 0).is_err());
+        assert!(mul_div(u128::MAX, 2, 1).is_err());
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX).unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn mul_div_is_monotonic() {
+        let mut rng = XorShift(0xfeed_face_0bad_f00d);
+        for _ in 0..SAMPLES {
+            let (a, b, d) = (rng.next_sized_u128() >> 1, rng.next_sized_u128(), rng.next_sized_u128().max(1));
+            let step = rng.next_sized_u128() >> 1;
+            if let (Ok(low), Ok(high)) = (mul_div(a, b, d), mul_div(a + step, b, d)) {
+                assert!(low <= high);
+            }
+            if let (Ok(low), Ok(high)) = (mul_div(a, b, d.saturating_add(step)), mul_div(a, b, d)) {
+                assert!(low <= high);
+            }
+        }
+    }
+
+    #[test]
+    fn apply_bps_bounds() {
+        let mut rng = XorShift(0x1357_9bdf_2468_ace0);
+        for _ in 0..SAMPLES {
+            let (amount, bps) = (rng.next_u64(), rng.next_u64() % 10_001);
+            let fee = apply_bps(amount, bps).unwrap();
+            assert!(fee <= amount);
+            assert!(fee <= apply_bps(amount, (bps + 1).min(10_000)).unwrap());
+        }
+        assert_eq!(apply_bps(u64::MAX, 10_000).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn checked_ops_fail_instead_of_wrapping() {
+        assert!(u64::MAX.safe_add(1).is_err());
+        assert!(0u64.safe_sub(1).is_err());
+        assert!(u128::MAX.safe_mul(2).is_err());
+        assert!(1i64.safe_div(0).is_err());
+        assert_eq!(7u32.safe_div(2).unwrap(), 3);
+    }
+}
@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::constants::{SEED_POOL, SEED_SEASON, SEED_SEASON_VAULT, SEED_SOL_VAULT};
+use crate::errors::CustomError;
+use crate::utils::SafeMath;
+use crate::state::{Pool, Season, StakeAsset};
+
+// ------------------------------------------------------------------
+// POOL VAULT
+// ------------------------------------------------------------------
+// Token pools hold stakes in the `pool_vault` token account (authority = Pool),
+// native SOL pools in the system-owned `sol_vault` PDA. Instructions pass the
+// accounts for the pool's asset and leave the others as None.
+
+pub struct VaultAccounts<'a, 'info> {
+    pub mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub pool_vault: Option<&'a mut Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+    pub sol_vault: Option<&'a SystemAccount<'info>>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Moves `amount` from `payer` (or its token account) into the vault.
+/// Returns what the vault actually received: Token-2022 transfer fees are withheld.
+pub fn deposit_to_vault<'info>(
+    pool: &Pool,
+    vault: &mut VaultAccounts<'_, 'info>,
+    payer: &AccountInfo<'info>,
+    payer_token_account: Option<&AccountInfo<'info>>,
+    amount: u64,
+) -> Result<u64> {
+    match pool.asset {
+        StakeAsset::Token => {
+            let mint = vault.mint.ok_or(CustomError::MissingVaultAccounts)?;
+            let token_program = vault.token_program.ok_or(CustomError::MissingVaultAccounts)?;
+            let from = payer_token_account.ok_or(CustomError::MissingVaultAccounts)?;
+            let pool_vault = vault.pool_vault.as_deref_mut().ok_or(CustomError::MissingVaultAccounts)?;
+
+            let vault_before = pool_vault.amount;
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.clone(),
+                        mint: mint.to_account_info(),
+                        to: pool_vault.to_account_info(),
+                        authority: payer.clone(),
+                    },
+                ),
+                amount,
+                mint.decimals,
+            )?;
+
+            pool_vault.reload()?;
+            Ok(pool_vault.amount.safe_sub(vault_before)?)
+        }
+        StakeAsset::NativeSol => {
+            let sol_vault = vault.sol_vault.ok_or(CustomError::MissingVaultAccounts)?;
+
+            system_program::transfer(
+                CpiContext::new(
+                    vault.system_program.to_account_info(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: sol_vault.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+
+            Ok(amount)
+        }
+    }
+}
+
+/// Moves `amount` from `payer` straight to `to` in the pool's asset
+/// (e.g. entry fees to the treasury), bypassing the vault.
+pub fn transfer_from_payer<'info>(
+    pool: &Pool,
+    vault: &VaultAccounts<'_, 'info>,
+    payer: &AccountInfo<'info>,
+    payer_token_account: Option<&AccountInfo<'info>>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    match pool.asset {
+        StakeAsset::Token => {
+            let mint = vault.mint.ok_or(CustomError::MissingVaultAccounts)?;
+            let token_program = vault.token_program.ok_or(CustomError::MissingVaultAccounts)?;
+            let from = payer_token_account.ok_or(CustomError::MissingVaultAccounts)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.clone(),
+                        mint: mint.to_account_info(),
+                        to: to.clone(),
+                        authority: payer.clone(),
+                    },
+                ),
+                amount,
+                mint.decimals,
+            )
+        }
+        StakeAsset::NativeSol => system_program::transfer(
+            CpiContext::new(
+                vault.system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: to.clone(),
+                },
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Pays `amount` out of the vault to `to` (a token account for token pools,
+/// a wallet for native SOL pools), signing with the Pool / vault PDA.
+pub fn withdraw_from_vault<'info>(
+    pool: &Account<'info, Pool>,
+    vault: &VaultAccounts<'_, 'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let pool_key = pool.key();
+
+    match pool.asset {
+        StakeAsset::Token => {
+            let mint = vault.mint.ok_or(CustomError::MissingVaultAccounts)?;
+            let token_program = vault.token_program.ok_or(CustomError::MissingVaultAccounts)?;
+            let pool_vault = vault.pool_vault.as_deref().ok_or(CustomError::MissingVaultAccounts)?;
+
+            withdraw_pool_tokens(pool, mint, token_program, &pool_vault.to_account_info(), to, amount)
+        }
+        StakeAsset::NativeSol => {
+            let sol_vault = vault.sol_vault.ok_or(CustomError::MissingVaultAccounts)?;
+
+            let bump = pool.sol_vault_bump;
+            let seeds = &[SEED_SOL_VAULT, pool_key.as_ref(), &[bump]];
+            let signer = &[&seeds[..]];
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    vault.system_program.to_account_info(),
+                    Transfer {
+                        from: sol_vault.to_account_info(),
+                        to: to.clone(),
+                    },
+                    signer,
+                ),
+                amount,
+            )
+        }
+    }
+}
+
+/// Pays `amount` of `mint` out of a token account owned by the Pool PDA
+/// (the pool vault, or a side vault such as the bonus reward vault).
+pub fn withdraw_pool_tokens<'info>(
+    pool: &Account<'info, Pool>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let name_bytes = pool.name.as_bytes();
+    let bump = pool.bump;
+    let seeds = &[SEED_POOL, name_bytes, &[bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.to_account_info(),
+                to: to.clone(),
+                authority: pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+
+// ------------------------------------------------------------------
+// SEASON VAULT
+// ------------------------------------------------------------------
+// Same layout as the pool vault: `pool_vault` carries the season's token
+// vault (authority = Season), `sol_vault` its system-owned SOL vault.
+
+/// Pays `amount` of prize funds out of the season vault to `to`.
+pub fn withdraw_from_season_vault<'info>(
+    season: &Account<'info, Season>,
+    vault: &VaultAccounts<'_, 'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let season_key = season.key();
+
+    match season.asset {
+        StakeAsset::Token => {
+            let mint = vault.mint.ok_or(CustomError::MissingVaultAccounts)?;
+            let token_program = vault.token_program.ok_or(CustomError::MissingVaultAccounts)?;
+            let season_vault = vault.pool_vault.as_deref().ok_or(CustomError::MissingVaultAccounts)?;
+
+            let season_id_bytes = season.id.to_le_bytes();
+            let seeds = &[SEED_SEASON, season_id_bytes.as_ref(), &[season.bump]];
+            let signer = &[&seeds[..]];
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: season_vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: to.clone(),
+                        authority: season.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+                mint.decimals,
+            )
+        }
+        StakeAsset::NativeSol => {
+            let sol_vault = vault.sol_vault.ok_or(CustomError::MissingVaultAccounts)?;
+
+            let seeds = &[SEED_SEASON_VAULT, season_key.as_ref(), &[season.vault_bump]];
+            let signer = &[&seeds[..]];
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    vault.system_program.to_account_info(),
+                    Transfer {
+                        from: sol_vault.to_account_info(),
+                        to: to.clone(),
+                    },
+                    signer,
+                ),
+                amount,
+            )
+        }
+    }
+}
\ No newline at end of file
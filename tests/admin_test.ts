@@ -187,7 +187,6 @@ describe("1. Admin & Config Tests", () => {
   // --- NEW TEST START ---
   it("Admin Action: Update Global Config", async () => {
     // 1. Prepare new values
-    const newTreasury = Keypair.generate(); // New random wallet
     const newHouseFee = new anchor.BN(150); // Change to 1.5%
     const newPariFee = new anchor.BN(300);  // Change to 3.0%
     
@@ -199,7 +198,7 @@ describe("1. Admin & Config Tests", () => {
     // 2. Call update_config
     await program.methods
       .updateConfig(
-        newTreasury.publicKey, // new_treasury
+        null,                  // new_treasury (deprecated)
         newPariFee,            // new_parimutuel_fee_bps
         newHouseFee,           // new_house_fee_bps
         newAllowedAssets       // new_allowed_assets
@@ -214,13 +213,13 @@ describe("1. Admin & Config Tests", () => {
     // 3. Verification
     const config = await program.account.globalConfig.fetch(globalConfigPda);
     
-    assert.ok(config.treasuryWallet.equals(newTreasury.publicKey), "Treasury wallet was not updated");
+    assert.ok(config.treasuryWallet.equals(treasury.publicKey), "Treasury wallet should no longer change");
     assert.equal(config.houseFeeBps.toNumber(), 150, "House fee was not updated");
     assert.equal(config.parimutuelFeeBps.toNumber(), 300, "Parimutuel fee was not updated");
     assert.equal(config.allowedAssets.length, 2, "Allowed assets list size mismatch");
     assert.ok(config.allowedAssets[1].equals(secondAsset), "Second asset not found in list");
 
-    console.log("Global Config Updated: Fees, Treasury, and Asset List modified");
+    console.log("Global Config Updated: Fees and Asset List modified");
   });
   // --- NEW TEST END ---
 
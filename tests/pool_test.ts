@@ -108,7 +108,13 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
   let usdcMint: PublicKey;
   let globalConfigPda: PublicKey;
   let poolPda: PublicKey;
-  let treasuryUsdcAta: PublicKey;
+  let seasonPda: PublicKey;
+  let seasonVaultPda: PublicKey;
+  let seasonEndTs: number;
+  let bonusMint: PublicKey;
+  let bonusVaultPda: PublicKey;
+  let treasuryPda: PublicKey;
+  let treasuryVaultPda: PublicKey;
   let userATAs: PublicKey[] = [];
   let admin_USDC: PublicKey;
 
@@ -116,6 +122,9 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
   const SEED_GLOBAL_CONFIG = Buffer.from("global_config_v1");
   const SEED_POOL = Buffer.from("pool");
   const SEED_BET = Buffer.from("user_bet");
+  const SEED_SEASON = Buffer.from("season");
+  const SEED_SEASON_VAULT = Buffer.from("season_vault");
+  const SEED_SEASON_ENTRY = Buffer.from("season_entry");
 
   const POOL_NAME = `SOL-Pool-${Math.floor(Math.random() * 1000)}`;
 
@@ -206,16 +215,12 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       [SEED_GLOBAL_CONFIG],
       program.programId
     );
-    let currentTreasuryWallet = admin.publicKey;
 
     try {
-      const configAccount = await program.account.globalConfig.fetch(
-        globalConfigPda
-      );
-      currentTreasuryWallet = configAccount.treasuryWallet;
-      
+      await program.account.globalConfig.fetch(globalConfigPda);
+
       await program.methods
-        .updateConfig(null, new anchor.BN(PROTOCOL_FEE_BPS))
+        .updateConfig(null, new anchor.BN(PROTOCOL_FEE_BPS), null)
         .accounts({
           admin: admin.publicKey,
           globalConfig: globalConfigPda,
@@ -232,18 +237,42 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           systemProgram: SystemProgram.programId,
         })
         .rpc();
-      currentTreasuryWallet = treasuryKey.publicKey;
     }
 
-    treasuryUsdcAta = (
-      await getOrCreateAssociatedTokenAccount(
-        provider.connection,
-        admin.payer,
-        usdcMint,
-        currentTreasuryWallet,
-        true
-      )
-    ).address;
+    // Delegation only targets trusted validators: trust the ER we test against
+    const { identity } = (
+      await (providerEphemeralRollup.connection as any)._rpcRequest("getIdentity", [])
+    ).result;
+    await program.methods
+      .setTrustedValidators([new PublicKey(identity)])
+      .accounts({
+        globalConfig: globalConfigPda,
+        admin: admin.publicKey,
+      })
+      .rpc();
+
+    // 4. Fee vault for the pool mint
+    [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), usdcMint.toBuffer()],
+      program.programId
+    );
+    [treasuryVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_vault"), usdcMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeTreasury(usdcMint, new anchor.BN(1_000_000))
+      .accounts({
+        admin: admin.publicKey,
+        globalConfig: globalConfigPda,
+        treasury: treasuryPda,
+        mint: usdcMint,
+        treasuryVault: treasuryVaultPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
   });
 
   describe("--- Pool Round (1 User with Privacy) ---", () => {
@@ -268,13 +297,16 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           POOL_NAME,
           startTime,
           endTime,
-          new anchor.BN(500), // max_accuracy_buffer
+          { absolute: { 0: new anchor.BN(500) } }, // accuracy_buffer
+          { linear: {} }, // accuracy_curve
           new anchor.BN(1000) // conviction_bonus_bps
         )
         .accounts({
           globalConfig: globalConfigPda,
           pool: poolPda,
+          mint: usdcMint,
           admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -284,6 +316,97 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       assert.equal(poolAccount.isResolved, false);
     });
 
+    it("Create Season and link the Pool", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      const seasonId = Math.floor(Math.random() * 0xffffffff);
+      const idBytes = Buffer.alloc(4);
+      idBytes.writeUInt32LE(seasonId);
+
+      [seasonPda] = PublicKey.findProgramAddressSync(
+        [SEED_SEASON, idBytes],
+        program.programId
+      );
+      [seasonVaultPda] = PublicKey.findProgramAddressSync(
+        [SEED_SEASON_VAULT, seasonPda.toBuffer()],
+        program.programId
+      );
+
+      // Leaves time to score the pool after it closes
+      seasonEndTs = now + DURATION_SECONDS + 30;
+
+      await program.methods
+        .createSeason(
+          seasonId,
+          new anchor.BN(now - 10),
+          new anchor.BN(seasonEndTs),
+          new anchor.BN(5000), // half of settlement fees
+          [10000] // winner takes the prize pool
+        )
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          season: seasonPda,
+          mint: usdcMint,
+          seasonVault: seasonVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .setPoolSeason()
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          pool: poolPda,
+          season: seasonPda,
+        })
+        .rpc();
+
+      // First round of a recurring series: no streak to carry in yet
+      await program.methods
+        .setPoolSeries(new anchor.BN(1), new anchor.BN(1), new anchor.BN(2000))
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          pool: poolPda,
+        })
+        .rpc();
+
+      const poolAccount = await program.account.pool.fetch(poolPda);
+      assert.ok(poolAccount.season.equals(seasonPda));
+      assert.equal(poolAccount.seriesRound.toNumber(), 1);
+    });
+
+    it("Quoted weight is monotonic in stake and accuracy", async () => {
+      const outcome = new anchor.BN(150_000_000);
+      const entryTs = new anchor.BN(Math.floor(Date.now() / 1000));
+      const quote = (prediction: anchor.BN, stake: anchor.BN) =>
+        program.methods
+          .quotePayout(prediction, stake, entryTs, outcome)
+          .accounts({ globalConfig: globalConfigPda, pool: poolPda })
+          .view();
+
+      // Stake: small deposits keep a non-zero weight, u64::MAX does not overflow
+      const stakes = ["1", "10", "1000", "50000000", "1000000000000", "18446744073709551615"];
+      let previous = new anchor.BN(0);
+      for (const stake of stakes) {
+        const q = await quote(outcome, new anchor.BN(stake));
+        assert.isTrue(q.weight.gt(previous), `weight should grow with stake ${stake}`);
+        previous = q.weight;
+      }
+
+      // Accuracy: weight never increases as the miss grows
+      previous = null;
+      for (const miss of [0, 1, 100, 250, 499, 500, 10_000]) {
+        const q = await quote(outcome.addn(miss), new anchor.BN(50_000_000));
+        if (previous) {
+          assert.isTrue(q.weight.lte(previous), `weight should not grow with miss ${miss}`);
+        }
+        previous = q.weight;
+      }
+    });
+
     // 1 User Data
     const requestId = "req_h_1";
     const userSalt = Keypair.generate().publicKey.toBuffer();
@@ -314,16 +437,12 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       );
 
       const [groupPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("group"), betPda.toBuffer()],
+        [Buffer.from("group:"), betPda.toBuffer()],
         ACCESS_CONTROL_PROGRAM_ID
       );
 
       const [permissionPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("permission"),
-          groupPda.toBuffer(),
-          user.publicKey.toBuffer(),
-        ],
+        [Buffer.from("permission:"), betPda.toBuffer()],
         ACCESS_CONTROL_PROGRAM_ID
       );
 
@@ -332,14 +451,16 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           .placeBet(
             betAmount,
             Array.from(commitment),
-            requestId
+            requestId,
+            null
           )
           .accounts({
             user: user.publicKey,
             globalConfig: globalConfigPda,
             pool: poolPda,
+            mint: usdcMint,
             userTokenAccount: userATAs[0],
-            treasuryWallet: treasuryUsdcAta,
+            treasuryVault: treasuryVaultPda,
             userBet: betPda,
             group: groupPda,
             permission: permissionPda,
@@ -353,6 +474,81 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       const betAccount = await program.account.userBet.fetch(betPda);
       assert.equal(betAccount.deposit.toNumber(), 50000000);
       assert.deepEqual(betAccount.commitment, Array.from(commitment));
+
+      const [profilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), user.publicKey.toBuffer()],
+        program.programId
+      );
+      const profile = await program.account.userProfile.fetch(profilePda);
+      assert.ok(profile.activePools.some((p) => p.equals(poolPda)));
+    });
+
+    it("Sponsor boosts the pot and funds a bonus reward", async () => {
+      const [poolVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pool_vault"), poolPda.toBuffer()],
+        program.programId
+      );
+      const before = await program.account.pool.fetch(poolPda);
+
+      await program.methods
+        .fundPool(new anchor.BN(10_000_000))
+        .accounts({
+          funder: admin.publicKey,
+          pool: poolPda,
+          mint: usdcMint,
+          poolVault: poolVaultPda,
+          funderTokenAccount: admin_USDC,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const after = await program.account.pool.fetch(poolPda);
+      assert.equal(after.sponsoredAmount.toNumber(), 10_000_000);
+      assert.equal(
+        after.vaultBalance.sub(before.vaultBalance).toNumber(),
+        10_000_000
+      );
+
+      // Secondary reward in a partner mint
+      bonusMint = await createMint(
+        provider.connection,
+        admin.payer,
+        admin.publicKey,
+        null,
+        6
+      );
+      const adminBonus = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          admin.payer,
+          bonusMint,
+          admin.publicKey
+        )
+      ).address;
+      await mintTo(provider.connection, admin.payer, bonusMint, adminBonus, admin.payer, 5_000_000);
+
+      [bonusVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bonus_vault"), poolPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .fundBonusReward(new anchor.BN(5_000_000))
+        .accounts({
+          funder: admin.publicKey,
+          pool: poolPda,
+          bonusMint,
+          bonusVault: bonusVaultPda,
+          funderTokenAccount: adminBonus,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const funded = await program.account.pool.fetch(poolPda);
+      assert.ok(funded.bonusMint.equals(bonusMint));
+      assert.equal(funded.bonusTotal.toNumber(), 5_000_000);
     });
 
     it("Delegate, Reveal (ER), Undelegate", async () => {
@@ -371,9 +567,10 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       console.log(`    🔒 Delegating Bet to TEE...`);
       await retryOp(async () => {
         await program.methods
-          .delegateBet(requestId)
+          .delegateBet(requestId, null, null)
           .accounts({
             user: user.publicKey,
+            globalConfig: globalConfigPda,
             pool: poolPda,
             userBet: betPda,
           })
@@ -404,6 +601,8 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           .accounts({ 
               user: user.publicKey, 
               userBet: betPda,
+              // The pool stays on L1 here; calculate_pool_outcome counts the reveal
+              pool: null,
               permissionProgram: ACCESS_CONTROL_PROGRAM_ID
            })
           .rpc();
@@ -452,6 +651,16 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
         program.programId
       );
 
+      const [bettorProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("profile"), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const [seasonEntryPda] = PublicKey.findProgramAddressSync(
+        [SEED_SEASON_ENTRY, seasonPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
       // Calculate Pool Outcome
       await program.methods
         .calculatePoolOutcome()
@@ -460,10 +669,24 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           betOwner: user.publicKey,
           pool: poolPda,
           userBet: betPda,
+          season: seasonPda,
+          seasonEntry: seasonEntryPda,
+          bettorProfile: bettorProfilePda,
         })
         .signers([user])
         .rpc();
 
+      const bettorProfile = await program.account.userProfile.fetch(bettorProfilePda);
+      const streak = bettorProfile.seriesStreaks.find((s) => s.seriesId.eqn(1));
+      assert.equal(streak.lastRound.toNumber(), 1);
+      assert.equal(streak.streak, 1, "Scoring round should start a streak");
+
+      const season = await program.account.season.fetch(seasonPda);
+      const seasonEntry = await program.account.userSeasonEntry.fetch(seasonEntryPda);
+      assert.ok(season.entries[0].user.equals(user.publicKey));
+      assert.isAbove(seasonEntry.points.toNumber(), 0);
+      assert.equal(season.entries[0].points.toNumber(), seasonEntry.points.toNumber());
+
       // Finalize Weights
       const [poolVaultPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("pool_vault"), poolPda.toBuffer()],
@@ -476,9 +699,12 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
           admin: admin.publicKey,
           globalConfig: globalConfigPda,
           pool: poolPda,
+          mint: usdcMint,
           poolVault: poolVaultPda,
-          treasuryWallet: treasuryUsdcAta,
+          treasuryVault: treasuryVaultPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          season: seasonPda,
+          seasonVault: seasonVaultPda,
         })
         .rpc();
 
@@ -497,6 +723,7 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
         .accounts({
           user: user.publicKey,
           pool: poolPda,
+          mint: usdcMint,
           poolVault: poolVaultPda,
           userBet: betPda,
           userTokenAccount: userATAs[0],
@@ -512,6 +739,123 @@ describe("Pool & Betting Tests (Parimutuel) - Single User with Privacy", () => {
       const payout = postBal - preBal;
       console.log(`    🎉 User Claimed: ${payout.toFixed(2)} USDC`);
       assert.isAbove(payout, 0, "User should have won");
+
+      const summary = await program.methods
+        .getPoolSummary()
+        .accounts({ pool: poolPda })
+        .view();
+      assert.isAtLeast(summary.stats.betCount.toNumber(), 1);
+      assert.isAtLeast(summary.stats.revealedCount.toNumber(), summary.stats.calculatedCount.toNumber());
+      assert.isAbove(summary.stats.totalClaimed.toNumber(), 0);
+
+      // Once the season is over the only ranked user takes the fee share
+      await sleep(Math.max(0, (seasonEndTs + 2) * 1000 - Date.now()));
+      const preSeasonBal = (
+        await provider.connection.getTokenAccountBalance(userATAs[0])
+      ).value.uiAmount!;
+
+      await program.methods
+        .claimSeasonPrize()
+        .accounts({
+          user: user.publicKey,
+          season: seasonPda,
+          mint: usdcMint,
+          seasonVault: seasonVaultPda,
+          userTokenAccount: userATAs[0],
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+      const postSeasonBal = (
+        await provider.connection.getTokenAccountBalance(userATAs[0])
+      ).value.uiAmount!;
+      assert.isAbove(postSeasonBal - preSeasonBal, 0, "Season winner should be paid");
+
+      // Only weighted bet: takes the whole bonus reward
+      const userBonus = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          admin.payer,
+          bonusMint,
+          user.publicKey
+        )
+      ).address;
+
+      await program.methods
+        .claimBonusReward()
+        .accounts({
+          user: user.publicKey,
+          pool: poolPda,
+          userBet: betPda,
+          bonusMint,
+          bonusVault: bonusVaultPda,
+          userTokenAccount: userBonus,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+      const bonusBal = await provider.connection.getTokenAccountBalance(userBonus);
+      assert.equal(bonusBal.value.amount, "5000000");
+    });
+
+    it("Treasury withdrawals respect the allowlist and epoch limit", async () => {
+      const withdraw = (amount: number, destination: PublicKey) =>
+        program.methods
+          .withdrawTreasury(new anchor.BN(amount))
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+            treasury: treasuryPda,
+            destination,
+            mint: usdcMint,
+            treasuryVault: treasuryVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+
+      // Empty allowlist: nothing can leave
+      try {
+        await withdraw(1, admin_USDC);
+        assert.fail("Withdrawal to a non-allowlisted destination should fail");
+      } catch (e: any) {
+        assert.include(e.toString(), "DestinationNotAllowed");
+      }
+
+      await program.methods
+        .setTreasuryPolicy([admin_USDC], new anchor.BN(1_000_000))
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          treasury: treasuryPda,
+        })
+        .rpc();
+
+      // The new policy only applies from the next epoch
+      try {
+        await withdraw(1, admin_USDC);
+        assert.fail("Queued policy should not apply in the current epoch");
+      } catch (e: any) {
+        assert.include(e.toString(), "DestinationNotAllowed");
+      }
+      const { epoch } = await provider.connection.getEpochInfo();
+      while ((await provider.connection.getEpochInfo()).epoch <= epoch) {
+        await new Promise((r) => setTimeout(r, 1000));
+      }
+
+      const preBal = (await provider.connection.getTokenAccountBalance(admin_USDC)).value.amount;
+      await withdraw(600_000, admin_USDC);
+      const postBal = (await provider.connection.getTokenAccountBalance(admin_USDC)).value.amount;
+      assert.equal(BigInt(postBal) - BigInt(preBal), BigInt(600_000));
+
+      // 600k + 600k exceeds the 1 USDC per-epoch limit
+      try {
+        await withdraw(600_000, admin_USDC);
+        assert.fail("Withdrawal above the epoch limit should fail");
+      } catch (e: any) {
+        assert.include(e.toString(), "TreasuryLimitExceeded");
+      }
     });
   });
 });
\ No newline at end of file